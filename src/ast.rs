@@ -1,153 +1,647 @@
-use crate::{ast_node::ASTNode, token::{Token, TokenType}};
+use std::{collections::HashMap, num::IntErrorKind};
+
+use crate::{ast_node::ASTNode, operator::UnaryOperator, parse_error::ParseError, precedence::{infix_operator, prefix_operator, InfixKind, Precedence}, span::Span, token::{Token, TokenType}};
 
 pub struct Parser {
     tokens: Vec<Token>,
     position: usize,
+    errors: Vec<ParseError>,
+    // Quantidade de laços envolvendo a instrução atual, usada para validar
+    // `pare` e `continue`
+    loop_depth: usize,
+    // Comentários `///`, indexados pela posição do token que vem logo
+    // depois deles
+    docs: HashMap<usize, String>,
 }
 
 impl Parser {
-    // Construtor do parser
-    pub fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, position: 0 }
+    // Construtor do parser. Os tokens sempre terminam em um único EOF: os
+    // tokens depois dele são descartados e, se ele faltar, é criado logo
+    // após o último token. Os comentários de documentação saem da lista de
+    // tokens, para que possam aparecer em qualquer lugar do código
+    pub fn new(mut tokens: Vec<Token>) -> Self {
+        match tokens.iter().position(|token| token._type == TokenType::EOF) {
+            Some(eof) => tokens.truncate(eof + 1),
+            None => {
+                let span = tokens.last()
+                    .map(|token| {
+                        let end = token.span.end_location();
+                        Span::new(token.span.file_id, end, end)
+                    })
+                    .unwrap_or_default();
+
+                tokens.push(Token::new(TokenType::EOF, String::new()).with_span(span));
+            }
+        }
+
+        let mut docs = HashMap::new();
+        let mut lines = Vec::new();
+        let mut code_tokens = Vec::with_capacity(tokens.len());
+
+        for token in tokens {
+            if token._type == TokenType::DOC_COMMENT {
+                lines.push(token.value);
+                continue;
+            }
+
+            if !lines.is_empty() {
+                docs.insert(code_tokens.len(), lines.join("\n"));
+                lines.clear();
+            }
+            code_tokens.push(token);
+        }
+
+        Self { tokens: code_tokens, position: 0, errors: Vec::new(), loop_depth: 0, docs }
     }
-    
-    // Retorna o token atual sem avançar
-    fn current_token(&self) -> Option<&Token> {
-        self.tokens.get(self.position)
+
+    // Erros de sintaxe registrados por `parse_program`
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
     }
 
-    // Avança para o próximo token
+    // Retorna o token atual sem avançar. Depois do fim, é sempre o EOF
+    fn current_token(&self) -> &Token {
+        &self.tokens[self.position]
+    }
+
+    // Retorna uma cópia do token atual
+    fn peek_token(&self) -> Token {
+        self.current_token().clone()
+    }
+
+    // Avança para o próximo token, sem nunca passar do EOF
     fn advance(&mut self) {
-        self.position += 1;
+        if !self.is_at_end() {
+            self.position += 1;
+        }
+    }
+
+    // Indica se todos os tokens já foram consumidos
+    fn is_at_end(&self) -> bool {
+        self.check(&TokenType::EOF)
     }
 
-    // Função principal de parse que retorna o AST
-    pub fn parse(&mut self) -> ASTNode {
-        if let Some(token) = self.current_token().cloned() {
-            if token._type == TokenType::KEYWORD && token.value == "var" {
-                return self.parse_variable_declaration();
+    // Indica se o token atual é do tipo informado
+    fn check(&self, _type: &TokenType) -> bool {
+        self.current_token()._type == *_type
+    }
+
+    // Indica se o token atual é a palavra-chave informada
+    fn check_keyword(&self, keyword: &str) -> bool {
+        let token = self.current_token();
+        token._type == TokenType::KEYWORD && token.value == keyword
+    }
+
+    // Consome o token atual se ele for do tipo esperado
+    fn expect(&mut self, _type: TokenType) -> Result<Token, ParseError> {
+        let token = self.peek_token();
+        if token._type != _type {
+            return Err(ParseError::unexpected_token(vec![_type], token));
+        }
+        self.advance();
+        Ok(token)
+    }
+
+    // Função principal de parse que retorna o AST de uma única instrução
+    // iniciada por palavra-chave ou bloco, ou de uma expressão
+    pub fn parse(&mut self) -> Result<ASTNode, ParseError> {
+        let result = if self.check(&TokenType::KEYWORD) || self.check(&TokenType::LBRACKET) {
+            self.parse_statement()
+        } else {
+            self.parse_expression()
+        };
+        result.map_err(|error| self.locate_error(error))
+    }
+
+    // Interpreta o arquivo inteiro, até o token EOF, como um programa.
+    // Em vez de parar no primeiro erro de sintaxe, registra o erro em
+    // `errors` e continua, retornando o AST com as instruções que puderam
+    // ser lidas
+    pub fn parse_program(&mut self) -> ASTNode {
+        ASTNode::Program(self.parse_statement_list(TokenType::EOF))
+    }
+
+    // Interpreta instruções até o fim dos tokens ou até o token `terminator`.
+    // Após um erro, descarta os tokens até um ponto seguro e segue para a
+    // próxima instrução
+    fn parse_statement_list(&mut self, terminator: TokenType) -> Vec<ASTNode> {
+        let mut statements = Vec::new();
+
+        loop {
+            if self.is_at_end() || self.check(&terminator) {
+                break;
+            }
+
+            let start = self.position;
+
+            match self.parse_statement() {
+                Ok(statement) => statements.push(statement),
+                Err(error) => {
+                    let error = self.locate_error(error);
+                    self.errors.push(error);
+                    self.synchronize(&terminator);
+
+                    // Garante que o parser sempre avance, mesmo quando o erro
+                    // acontece no próprio ponto de sincronização
+                    if self.position == start {
+                        self.advance();
+                    }
+                }
+            }
+        }
+
+        statements
+    }
+
+    // Um erro no fim do arquivo é informado logo após o último token com
+    // código, e não no fim real do arquivo, depois dos espaços e comentários
+    // finais
+    fn locate_error(&self, mut error: ParseError) -> ParseError {
+        if error.found._type == TokenType::EOF && self.position > 0 {
+            let end = self.tokens[self.position - 1].span.end_location();
+            error.found.span = Span::new(error.found.span.file_id, end, end);
+        }
+        error
+    }
+
+    // Descarta tokens até o fim da instrução atual: logo após um ';' ou um
+    // '}', ou antes do token que encerra a lista de instruções atual
+    fn synchronize(&mut self, terminator: &TokenType) {
+        while !self.is_at_end() && !self.check(terminator) {
+            let _type = self.current_token()._type.clone();
+            self.advance();
+
+            if _type == TokenType::SEMICOLON || _type == TokenType::RBRACKET {
+                return;
             }
         }
-        self.parse_expression()
     }
 
-    fn parse_variable_declaration(&mut self) -> ASTNode {
+    // Interpreta uma lista de instruções entre '{' e '}'
+    fn parse_block(&mut self) -> Result<ASTNode, ParseError> {
+        self.expect(TokenType::LBRACKET)?;
+        let statements = self.parse_statement_list(TokenType::RBRACKET);
+        self.expect(TokenType::RBRACKET)?;
+
+        Ok(ASTNode::Block(statements))
+    }
+
+    // Interpreta uma instrução: uma declaração, um bloco ou uma expressão
+    // seguida de ';'
+    fn parse_statement(&mut self) -> Result<ASTNode, ParseError> {
+        // A documentação só é associada a declarações; nas demais instruções
+        // ela é ignorada
+        let doc = self.docs.remove(&self.position);
+
+        if self.check_keyword("var") {
+            return self.parse_variable_declaration(doc);
+        }
+
+        if self.check_keyword("função") {
+            return self.parse_function_declaration(doc);
+        }
+
+        if self.check_keyword("retorne") {
+            return self.parse_return();
+        }
+
+        if self.check_keyword("para") {
+            return self.parse_for();
+        }
+
+        if self.check_keyword("se") {
+            return self.parse_if();
+        }
+
+        if self.check_keyword("enquanto") {
+            return self.parse_while();
+        }
+
+        if self.check_keyword("pare") || self.check_keyword("continue") {
+            return self.parse_loop_control();
+        }
+
+        if self.check(&TokenType::LBRACKET) {
+            return self.parse_block();
+        }
+
+        let expr = self.parse_expression()?;
+        self.expect(TokenType::SEMICOLON)?;
+        Ok(expr)
+    }
+
+    fn parse_variable_declaration(&mut self, doc: Option<String>) -> Result<ASTNode, ParseError> {
         // Consome o token "var"
         self.advance();
 
         // Espera um identificador após "var"
-        let id_token = self.current_token().cloned()
-            .expect("Esperava um identificador após 'var'");
-        if id_token._type != TokenType::IDENTIFIER {
-            panic!("Esperava um identificador, encontrou: {:?}", id_token);
-        }
-        self.advance();
+        let id_token = self.expect(TokenType::IDENTIFIER)?;
 
         // Espera o token de atribuição "="
-        let assign_token = self.current_token().cloned()
-            .expect("Esperava '=' após o identificador");
-        if assign_token._type != TokenType::ASSIGNMENT {
-            panic!("Esperava '=', encontrou: {:?}", assign_token);
-        }
-        self.advance();
+        self.expect(TokenType::EQUAL)?;
 
         // Interpreta a expressão do lado direito do '='
-        let expr = self.parse_expression();
+        let expr = self.parse_expression()?;
 
         // Espera um ponto e vírgula ao final da declaração
-        let semicolon_token = self.current_token().cloned()
-            .expect("Esperava ';' ao final da declaração");
-        if semicolon_token._type != TokenType::SEMICOLON {
-            panic!("Esperava ';', encontrou: {:?}", semicolon_token);
-        }
-        self.advance();
+        self.expect(TokenType::SEMICOLON)?;
 
-        ASTNode::VariableDeclaration {
+        Ok(ASTNode::VariableDeclaration {
             identifier: id_token.value,
             value: Box::new(expr),
-        }
+            doc,
+        })
     }
 
-    // Analisa uma expressão que, neste caso, pode ser uma operação binária
-    fn parse_expression(&mut self) -> ASTNode {
-        // Começamos com a análise de termos, considerando a precedência de operadores
-        let mut left = self.parse_term();
-
-        // Analisa os operadores de soma e subtração
-        while let Some(token) = self.current_token().cloned() {
-            if token._type == TokenType::OPERATOR && (token.value == "+" || token.value == "-") {
-                let op = token.clone();
+    fn parse_function_declaration(&mut self, doc: Option<String>) -> Result<ASTNode, ParseError> {
+        // Consome o token "função"
+        self.advance();
+
+        let name_token = self.expect(TokenType::IDENTIFIER)?;
+
+        // Lista de parâmetros: (a, b, ...)
+        self.expect(TokenType::LPAREN)?;
+        let mut params = Vec::new();
+
+        if !self.check(&TokenType::RPAREN) {
+            loop {
+                params.push(self.expect(TokenType::IDENTIFIER)?.value);
+
+                if !self.check(&TokenType::COMMA) {
+                    break;
+                }
                 self.advance();
-                let right = self.parse_term();
-                left = ASTNode::BinaryOp {
-                    left: Box::new(left),
-                    op,
-                    right: Box::new(right),
-                };
-            } else {
-                break;
             }
         }
 
-        left
+        let token = self.peek_token();
+        if token._type != TokenType::RPAREN {
+            return Err(ParseError::unexpected_token(vec![TokenType::COMMA, TokenType::RPAREN], token));
+        }
+        self.advance();
+
+        // `pare` e `continue` não atravessam o corpo de uma função
+        let loop_depth = std::mem::replace(&mut self.loop_depth, 0);
+        let body = self.parse_block();
+        self.loop_depth = loop_depth;
+        let body = body?;
+
+        Ok(ASTNode::FunctionDeclaration {
+            name: name_token.value,
+            params,
+            body: Box::new(body),
+            doc,
+        })
     }
 
-    // Processa um "termo": pode ser um número ou um identificador
-    fn parse_term(&mut self) -> ASTNode {
-        let mut left = self.parse_factor();
+    fn parse_return(&mut self) -> Result<ASTNode, ParseError> {
+        // Consome o token "retorne"
+        self.advance();
 
-        // Analisa multiplicação e divisão
-        while let Some(token) = self.current_token().cloned() {
-            if token._type == TokenType::OPERATOR && (token.value == "*" || token.value == "/") {
-                let op = token.clone();
-                self.advance();
-                let right = self.parse_factor();
-                left = ASTNode::BinaryOp {
-                    left: Box::new(left),
-                    op,
-                    right: Box::new(right),
-                };
+        // `retorne;` não carrega nenhum valor
+        if self.check(&TokenType::SEMICOLON) {
+            self.advance();
+            return Ok(ASTNode::Return(None));
+        }
+
+        let value = self.parse_expression()?;
+        self.expect(TokenType::SEMICOLON)?;
+
+        Ok(ASTNode::Return(Some(Box::new(value))))
+    }
+
+    fn parse_if(&mut self) -> Result<ASTNode, ParseError> {
+        // Consome o token "se"
+        self.advance();
+
+        self.expect(TokenType::LPAREN)?;
+        let condition = self.parse_expression()?;
+        self.expect(TokenType::RPAREN)?;
+
+        let then_branch = self.parse_block()?;
+
+        // `senão se` encadeia outra condicional; `senão` sozinho abre um bloco
+        let else_branch = if self.check_keyword("senão") {
+            self.advance();
+
+            if self.check_keyword("se") {
+                Some(Box::new(self.parse_if()?))
             } else {
+                Some(Box::new(self.parse_block()?))
+            }
+        } else {
+            None
+        };
+
+        Ok(ASTNode::If {
+            condition: Box::new(condition),
+            then_branch: Box::new(then_branch),
+            else_branch,
+        })
+    }
+
+    fn parse_while(&mut self) -> Result<ASTNode, ParseError> {
+        // Consome o token "enquanto"
+        self.advance();
+
+        self.expect(TokenType::LPAREN)?;
+        let condition = self.parse_expression()?;
+        self.expect(TokenType::RPAREN)?;
+
+        let body = self.parse_loop_body()?;
+
+        Ok(ASTNode::While {
+            condition: Box::new(condition),
+            body: Box::new(body),
+        })
+    }
+
+    // Interpreta o bloco de um laço, onde `pare` e `continue` são permitidos
+    fn parse_loop_body(&mut self) -> Result<ASTNode, ParseError> {
+        self.loop_depth += 1;
+        let body = self.parse_block();
+        self.loop_depth -= 1;
+        body
+    }
+
+    // Interpreta `pare;` ou `continue;`
+    fn parse_loop_control(&mut self) -> Result<ASTNode, ParseError> {
+        let token = self.peek_token();
+        self.advance();
+
+        if self.loop_depth == 0 {
+            return Err(ParseError::outside_loop(token));
+        }
+
+        self.expect(TokenType::SEMICOLON)?;
+
+        if token.value == "pare" {
+            Ok(ASTNode::Break)
+        } else {
+            Ok(ASTNode::Continue)
+        }
+    }
+
+    fn parse_for(&mut self) -> Result<ASTNode, ParseError> {
+        // Consome o token "para"
+        self.advance();
+        self.expect(TokenType::LPAREN)?;
+
+        // Inicialização: uma declaração com `var` (que já consome o ';'),
+        // uma atribuição ou nada
+        let init = if self.check_keyword("var") {
+            Some(Box::new(self.parse_variable_declaration(None)?))
+        } else if self.check(&TokenType::SEMICOLON) {
+            self.advance();
+            None
+        } else {
+            let init = self.parse_expression()?;
+            self.expect(TokenType::SEMICOLON)?;
+            Some(Box::new(init))
+        };
+
+        // Condição de parada
+        let condition = if self.check(&TokenType::SEMICOLON) {
+            None
+        } else {
+            Some(Box::new(self.parse_expression()?))
+        };
+        self.expect(TokenType::SEMICOLON)?;
+
+        // Atualização executada ao final de cada iteração
+        let update = if self.check(&TokenType::RPAREN) {
+            None
+        } else {
+            Some(Box::new(self.parse_expression()?))
+        };
+        self.expect(TokenType::RPAREN)?;
+
+        let body = self.parse_loop_body()?;
+
+        Ok(ASTNode::For {
+            init,
+            condition,
+            update,
+            body: Box::new(body),
+        })
+    }
+
+    // Analisa uma expressão que, neste caso, pode ser uma operação binária
+    fn parse_expression(&mut self) -> Result<ASTNode, ParseError> {
+        self.parse_expression_bp(Precedence::Lowest.binding_power())
+    }
+
+    // Parser Pratt: lê um operando e, enquanto o próximo operador se ligar
+    // com força de pelo menos `min_bp`, junta-o ao operando seguinte. A
+    // precedência e a associatividade de cada operador vêm da tabela em
+    // `precedence`
+    fn parse_expression_bp(&mut self, min_bp: u8) -> Result<ASTNode, ParseError> {
+        let mut left = self.parse_unary()?;
+
+        while let Some(operator) = infix_operator(self.current_token()) {
+            let (left_bp, right_bp) = operator.binding_power();
+            if left_bp < min_bp {
                 break;
             }
+
+            left = match operator.kind {
+                InfixKind::Assignment(op) => {
+                    let ASTNode::Identifier(identifier) = left else {
+                        return Err(ParseError::invalid_assignment_target(self.peek_token()));
+                    };
+                    self.advance();
+
+                    ASTNode::Assignment {
+                        identifier,
+                        op,
+                        value: Box::new(self.parse_expression_bp(right_bp)?),
+                    }
+                },
+                InfixKind::Logical(op) => {
+                    self.advance();
+
+                    ASTNode::LogicalOp {
+                        left: Box::new(left),
+                        op,
+                        right: Box::new(self.parse_expression_bp(right_bp)?),
+                    }
+                },
+                InfixKind::Binary(op) => {
+                    self.advance();
+
+                    ASTNode::BinaryOp {
+                        left: Box::new(left),
+                        op,
+                        right: Box::new(self.parse_expression_bp(right_bp)?),
+                    }
+                },
+            };
+        }
+
+        Ok(left)
+    }
+
+    // Analisa os operadores prefixos, como `-`, `+` e `!`. O operando só
+    // inclui operadores com precedência maior que a dos prefixos
+    fn parse_unary(&mut self) -> Result<ASTNode, ParseError> {
+        let Some(op) = prefix_operator(self.current_token()) else {
+            return self.parse_factor();
+        };
+
+        self.advance();
+
+        // `-` seguido de um literal numérico vira o próprio literal negativo,
+        // para que `-9223372036854775808` caiba em um i64. Com `**`, que se
+        // liga mais forte que o prefixo, `-2 ** 2` continua sendo `-(2 ** 2)`
+        if op == UnaryOperator::Negate && self.check(&TokenType::NUMBER) && self.tokens[self.position + 1]._type != TokenType::STAR_STAR {
+            let token = self.peek_token();
+            self.advance();
+            return self.parse_number(token, true);
+        }
+
+        let operand = self.parse_expression_bp(Precedence::Unary.binding_power())?;
+
+        Ok(ASTNode::UnaryOp {
+            op,
+            operand: Box::new(operand),
+        })
+    }
+
+    // Processa um "fator": um valor primário seguido de zero ou mais chamadas
+    fn parse_factor(&mut self) -> Result<ASTNode, ParseError> {
+        let mut expr = self.parse_primary()?;
+
+        // Chamadas encadeadas, como `f(x)(y)`
+        while self.check(&TokenType::LPAREN) {
+            self.advance();
+            let args = self.parse_arguments()?;
+            expr = ASTNode::Call {
+                callee: Box::new(expr),
+                args,
+            };
+        }
+
+        Ok(expr)
+    }
+
+    // Interpreta os argumentos de uma chamada até o ')', que também é consumido
+    fn parse_arguments(&mut self) -> Result<Vec<ASTNode>, ParseError> {
+        let mut args = Vec::new();
+
+        if self.check(&TokenType::RPAREN) {
+            self.advance();
+            return Ok(args);
+        }
+
+        loop {
+            args.push(self.parse_expression()?);
+
+            let token = self.peek_token();
+            match token._type {
+                TokenType::COMMA => self.advance(),
+                TokenType::RPAREN => {
+                    self.advance();
+                    return Ok(args);
+                },
+                _ => return Err(ParseError::unexpected_token(vec![TokenType::COMMA, TokenType::RPAREN], token)),
+            }
+        }
+    }
+
+    // Converte o valor de um token NUMBER em um nó `Number` ou `Float`
+    fn parse_number(&self, token: Token, negative: bool) -> Result<ASTNode, ParseError> {
+        let radix = match token.value.get(..2) {
+            Some("0x") | Some("0X") => 16,
+            Some("0b") | Some("0B") => 2,
+            Some("0o") | Some("0O") => 8,
+            _ => 10,
+        };
+
+        // Números decimais com ponto ou expoente são de ponto flutuante
+        if radix == 10 && token.value.contains(['.', 'e', 'E']) {
+            let value = match token.value.parse::<f64>() {
+                Ok(value) => value,
+                Err(_) => return Err(ParseError::invalid_number(token)),
+            };
+
+            // Literais como `1e999` viram infinito ao serem convertidos
+            if !value.is_finite() {
+                return Err(ParseError::float_overflow(token));
+            }
+            return Ok(ASTNode::Float(if negative { -value } else { value }));
         }
 
-        left
+        let digits = if radix == 10 { &token.value[..] } else { &token.value[2..] };
+        let digits = if negative { format!("-{}", digits) } else { digits.to_string() };
+
+        match i64::from_str_radix(&digits, radix) {
+            Ok(value) => Ok(ASTNode::Number(value)),
+            Err(error) if matches!(error.kind(), IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => {
+                Err(ParseError::integer_overflow(token))
+            },
+            Err(_) => Err(ParseError::invalid_number(token)),
+        }
     }
 
-    fn parse_factor(&mut self) -> ASTNode {
-        if let Some(token) = self.current_token().cloned() {
+    // Interpreta uma f-string a partir dos tokens gerados pelo lexer: cada
+    // trecho de texto vira um `String` e cada interpolação, uma expressão
+    fn parse_interpolated_string(&mut self) -> Result<ASTNode, ParseError> {
+        self.expect(TokenType::FSTRING_START)?;
+        let mut parts = Vec::new();
+
+        loop {
+            let token = self.peek_token();
             match token._type {
-                TokenType::NUMBER => {
+                TokenType::FSTRING_TEXT => {
                     self.advance();
-                    let value = token.value.parse::<i64>().expect("Número inválido");
-                    ASTNode::Number(value)
+                    parts.push(ASTNode::String(token.value));
                 },
-                TokenType::IDENTIFIER => {
+                TokenType::INTERPOLATION_START => {
                     self.advance();
-                    ASTNode::Identifier(token.value)
+                    parts.push(self.parse_expression()?);
+                    self.expect(TokenType::INTERPOLATION_END)?;
                 },
-                TokenType::LPAREN => {
-                    // Abre parênteses, parse a expressão interna
+                TokenType::FSTRING_END => {
                     self.advance();
-                    let expr = self.parse_expression();
-    
-                    // Espera o fechamento do parêntese
-                    if let Some(token) = self.current_token().cloned() {
-                        if token._type == TokenType::RPAREN {
-                            self.advance();
-                            expr
-                        } else {
-                            panic!("Esperava ')', encontrou: {:?}", token);
-                        }
-                    } else {
-                        panic!("Esperava ')', mas não encontrou");
-                    }
+                    return Ok(ASTNode::InterpolatedString(parts));
                 },
-                _ => panic!("Token inesperado: {:?}", token),
+                _ => return Err(ParseError::unexpected_token(
+                    vec![TokenType::FSTRING_TEXT, TokenType::INTERPOLATION_START, TokenType::FSTRING_END],
+                    token,
+                )),
             }
-        } else {
-            panic!("Fim dos tokens inesperado");
         }
     }
-}
\ No newline at end of file
+
+    fn parse_primary(&mut self) -> Result<ASTNode, ParseError> {
+        let token = self.peek_token();
+        match token._type {
+            TokenType::NUMBER => {
+                self.advance();
+                self.parse_number(token, false)
+            },
+            TokenType::STRING => {
+                self.advance();
+                Ok(ASTNode::String(token.value))
+            },
+            TokenType::FSTRING_START => self.parse_interpolated_string(),
+            TokenType::IDENTIFIER => {
+                self.advance();
+                Ok(ASTNode::Identifier(token.value))
+            },
+            TokenType::LPAREN => {
+                // Abre parênteses, parse a expressão interna
+                self.advance();
+                let expr = self.parse_expression()?;
+
+                // Espera o fechamento do parêntese
+                self.expect(TokenType::RPAREN)?;
+                Ok(expr)
+            },
+            _ => Err(ParseError::unexpected_token(
+                vec![TokenType::NUMBER, TokenType::STRING, TokenType::FSTRING_START, TokenType::IDENTIFIER, TokenType::LPAREN],
+                token,
+            )),
+        }
+    }
+}
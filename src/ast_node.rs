@@ -1,16 +1,81 @@
-use crate::token::Token;
+use crate::operator::{AssignOperator, BinaryOperator, LogicalOperator, UnaryOperator};
 
 #[derive(Debug, PartialEq)]
 pub enum ASTNode {
     Number(i64),
+    Float(f64),
+    String(String),
+    // f-string: trechos de texto (`String`) intercalados com expressões
+    InterpolatedString(Vec<ASTNode>),
     Identifier(String),
     BinaryOp {
         left: Box<ASTNode>,
-        op: Token,
+        op: BinaryOperator,
+        right: Box<ASTNode>,
+    },
+    // Operações prefixas `-`, `+`, `!` e `~`
+    UnaryOp {
+        op: UnaryOperator,
+        operand: Box<ASTNode>,
+    },
+    // Operações lógicas `&&` e `||`, separadas de `BinaryOp` porque o lado
+    // direito só é avaliado quando necessário (curto-circuito)
+    LogicalOp {
+        left: Box<ASTNode>,
+        op: LogicalOperator,
         right: Box<ASTNode>,
     },
     VariableDeclaration {
         identifier: String,
         value: Box<ASTNode>,
+        // Comentários `///` que precedem a declaração
+        doc: Option<String>,
+    },
+    FunctionDeclaration {
+        name: String,
+        params: Vec<String>,
+        body: Box<ASTNode>,
+        // Comentários `///` que precedem a declaração
+        doc: Option<String>,
+    },
+    // Chamada de função: `callee(arg1, arg2, ...)`
+    Call {
+        callee: Box<ASTNode>,
+        args: Vec<ASTNode>,
+    },
+    // Atribuição simples (`=`) ou composta (`+=`, `<<=`, ...)
+    Assignment {
+        identifier: String,
+        op: AssignOperator,
+        value: Box<ASTNode>,
+    },
+    // Laço `para (init; condição; atualização) { ... }`, com cláusulas opcionais
+    For {
+        init: Option<Box<ASTNode>>,
+        condition: Option<Box<ASTNode>>,
+        update: Option<Box<ASTNode>>,
+        body: Box<ASTNode>,
+    },
+    // Laço `enquanto (condição) { ... }`
+    While {
+        condition: Box<ASTNode>,
+        body: Box<ASTNode>,
+    },
+    // `pare`: encerra o laço atual
+    Break,
+    // `continue`: segue para a próxima iteração do laço atual
+    Continue,
+    // Condicional `se (condição) { ... } senão { ... }`. Em uma cadeia
+    // `senão se`, o `else_branch` é outro `If`
+    If {
+        condition: Box<ASTNode>,
+        then_branch: Box<ASTNode>,
+        else_branch: Option<Box<ASTNode>>,
     },
+    // `retorne` com ou sem valor
+    Return(Option<Box<ASTNode>>),
+    // Arquivo inteiro: a lista de instruções de nível superior
+    Program(Vec<ASTNode>),
+    // Lista de instruções delimitada por '{' e '}'
+    Block(Vec<ASTNode>),
 }
\ No newline at end of file
@@ -0,0 +1,110 @@
+use std::{error::Error, fmt};
+
+use crate::span::Span;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LexErrorKind {
+    // Caractere que não começa nenhum token
+    InvalidChar { found: char },
+    // String ou f-string sem o delimitador de fechamento
+    UnterminatedString { delim: char },
+    // Sequência de escape desconhecida, como `\q`
+    InvalidEscape { sequence: String },
+    // Literal numérico mal formado, como `1.2.3` ou `0b102`
+    MalformedNumber { literal: String },
+    // Comentário `/* ... */` sem o fechamento
+    UnterminatedComment,
+    // '}' isolado no texto de uma f-string
+    UnmatchedBrace,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub span: Span,
+}
+
+impl LexError {
+    pub fn invalid_char(found: char, span: Span) -> Self {
+        Self {
+            kind: LexErrorKind::InvalidChar { found },
+            span,
+        }
+    }
+
+    pub fn unterminated_string(delim: char, span: Span) -> Self {
+        Self {
+            kind: LexErrorKind::UnterminatedString { delim },
+            span,
+        }
+    }
+
+    pub fn invalid_escape(sequence: String, span: Span) -> Self {
+        Self {
+            kind: LexErrorKind::InvalidEscape { sequence },
+            span,
+        }
+    }
+
+    pub fn malformed_number(literal: String, span: Span) -> Self {
+        Self {
+            kind: LexErrorKind::MalformedNumber { literal },
+            span,
+        }
+    }
+
+    pub fn unterminated_comment(span: Span) -> Self {
+        Self {
+            kind: LexErrorKind::UnterminatedComment,
+            span,
+        }
+    }
+
+    pub fn unmatched_brace(span: Span) -> Self {
+        Self {
+            kind: LexErrorKind::UnmatchedBrace,
+            span,
+        }
+    }
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (line, column) = (self.span.start_line, self.span.start_column);
+
+        match &self.kind {
+            LexErrorKind::InvalidChar { found } => write!(
+                f,
+                "Caractere inválido encontrado '{}' na linha {} e coluna {}",
+                found, line, column
+            ),
+            LexErrorKind::UnterminatedString { delim } => write!(
+                f,
+                "Erro: esperado fechamento da string ({}) iniciada na linha {} e coluna {} antes do fim do arquivo.",
+                delim, line, column
+            ),
+            LexErrorKind::InvalidEscape { sequence } => write!(
+                f,
+                "Sequência de escape inválida '{}' na linha {} e coluna {}",
+                sequence, line, column
+            ),
+            LexErrorKind::MalformedNumber { literal } => write!(
+                f,
+                "Número malformado '{}' iniciado na linha {} e coluna {}",
+                literal, line, column
+            ),
+            LexErrorKind::UnterminatedComment => write!(
+                f,
+                "Erro: esperado fechamento do comentário (*/) iniciado na linha {} e coluna {} antes do fim do arquivo.",
+                line, column
+            ),
+            LexErrorKind::UnmatchedBrace => write!(
+                f,
+                "'}}' sem '{{' correspondente na f-string na linha {} e coluna {} (use '}}}}' para escrever '}}')",
+                line, column
+            ),
+        }
+    }
+}
+
+impl Error for LexError {}
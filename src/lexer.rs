@@ -1,145 +1,621 @@
-use std::error::Error;
+use std::collections::VecDeque;
 
-use crate::{lexer_cursor::LexerCursor, token::{Token, TokenType}};
+use crate::{lex_error::LexError, lexer_cursor::LexerCursor, span::{Location, Span}, token::{Token, TokenType}};
 
-const KEYWORDS: &[&str] = &["função", "var", "para", "retorne"];
+const KEYWORDS: &[&str] = &["função", "var", "para", "retorne", "se", "senão", "enquanto", "pare", "continue"];
 
-pub struct Lexer {
-    cursor: LexerCursor
+// Operadores e atribuições, dos mais longos para os mais curtos, para que
+// `<<=` seja reconhecido antes de `<<` e `<`
+const OPERATORS: &[(&str, TokenType)] = &[
+    ("<<=", TokenType::LESS_LESS_EQUAL),
+    (">>=", TokenType::GREATER_GREATER_EQUAL),
+    ("**=", TokenType::STAR_STAR_EQUAL),
+    ("+=", TokenType::PLUS_EQUAL),
+    ("-=", TokenType::MINUS_EQUAL),
+    ("*=", TokenType::STAR_EQUAL),
+    ("/=", TokenType::SLASH_EQUAL),
+    ("%=", TokenType::PERCENT_EQUAL),
+    ("&=", TokenType::AMPERSAND_EQUAL),
+    ("|=", TokenType::PIPE_EQUAL),
+    ("^=", TokenType::CARET_EQUAL),
+    ("==", TokenType::EQUAL_EQUAL),
+    ("!=", TokenType::BANG_EQUAL),
+    ("<=", TokenType::LESS_EQUAL),
+    (">=", TokenType::GREATER_EQUAL),
+    ("&&", TokenType::AMPERSAND_AMPERSAND),
+    ("||", TokenType::PIPE_PIPE),
+    ("**", TokenType::STAR_STAR),
+    ("<<", TokenType::LESS_LESS),
+    (">>", TokenType::GREATER_GREATER),
+    ("=", TokenType::EQUAL),
+    ("+", TokenType::PLUS),
+    ("-", TokenType::MINUS),
+    ("*", TokenType::STAR),
+    ("/", TokenType::SLASH),
+    ("%", TokenType::PERCENT),
+    ("<", TokenType::LESS),
+    (">", TokenType::GREATER),
+    ("!", TokenType::BANG),
+    ("&", TokenType::AMPERSAND),
+    ("|", TokenType::PIPE),
+    ("^", TokenType::CARET),
+    ("~", TokenType::TILDE),
+];
+
+pub struct Lexer<'a> {
+    cursor: LexerCursor<'a>,
+    file_id: usize,
+    // Tokens já lidos que ainda não foram entregues. Uma f-string, por
+    // exemplo, produz vários tokens de uma só vez
+    pending: VecDeque<Token>,
+    // Depois do EOF ou de um erro o lexer não produz mais tokens. No modo de
+    // recuperação, os erros não encerram a leitura
+    finished: bool,
+    recover: bool,
+    errors: Vec<LexError>
 }
 
-impl Lexer {
-    pub fn new(input: String) -> Self {
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self::with_file_id(input, 0)
+    }
+
+    // Cria um lexer cujos tokens são associados ao arquivo `file_id`
+    pub fn with_file_id(input: &'a str, file_id: usize) -> Self {
         Self {
-            cursor: LexerCursor::new(input)
+            cursor: LexerCursor::new(input),
+            file_id,
+            pending: VecDeque::new(),
+            finished: false,
+            recover: false,
+            errors: Vec::new()
         }
     }
 
+    // Ativa o modo de recuperação: cada trecho inválido vira um token ERROR,
+    // o erro é guardado em `errors` e a leitura continua
+    pub fn with_recovery(mut self) -> Self {
+        self.recover = true;
+        self
+    }
+
+    // Erros encontrados no modo de recuperação
+    pub fn errors(&self) -> &[LexError] {
+        &self.errors
+    }
+
     pub fn is_keyword(&self, value: String) -> bool {
-        return KEYWORDS.contains(&value.as_str());
+        KEYWORDS.contains(&value.as_str())
+    }
+
+    // Trecho que vai de `start` até o caractere atual do cursor (inclusive)
+    fn span_from(&self, start: Location) -> Span {
+        Span::new(self.file_id, start, self.cursor.end_location())
+    }
+
+    fn push_token(&mut self, _type: TokenType, value: char) {
+        let span = self.span_from(self.cursor.location());
+        self.pending.push_back(Token::new(_type, value.to_string()).with_span(span));
+    }
+
+    // Adiciona um token de vários caracteres, como `==` ou `&&`, avançando o
+    // cursor até o último caractere dele
+    fn push_multi_char_token(&mut self, _type: TokenType, value: &str) {
+        let start = self.cursor.location();
+
+        for _ in 1..value.chars().count() {
+            self.cursor.advance();
+        }
+
+        self.pending.push_back(Token::new(_type, value.to_string()).with_span(self.span_from(start)));
     }
 
-    fn push_token(&mut self, _type: TokenType, value: char, tokens: &mut Vec<Token>) {
-        tokens.push(Token::new(_type, value.to_string()));
+    fn invalid_char_error(&self) -> LexError {
+        LexError::invalid_char(self.cursor.current_char, self.span_from(self.cursor.location()))
+    }
+
+    // Ignora um comentário `// ...` até o fim da linha. O cursor termina no
+    // último caractere do comentário
+    fn skip_line_comment(&mut self) {
+        while self.cursor.peek().is_some_and(|c| c != '\n') {
+            self.cursor.advance();
+        }
+    }
+
+    // Lê um comentário de documentação `/// ...` até o fim da linha. O valor
+    // do token é o texto após as barras, sem o primeiro espaço
+    fn read_doc_comment(&mut self) -> Token {
+        let start = self.cursor.location();
+
+        // Consome as três barras
+        self.cursor.advance();
+        self.cursor.advance();
+
+        let mut text = String::new();
+        while self.cursor.peek().is_some_and(|c| c != '\n') {
+            self.cursor.advance();
+            text.push(self.cursor.current_char);
+        }
+
+        let text = text.strip_prefix(' ').unwrap_or(&text).trim_end().to_string();
+        Token::new(TokenType::DOC_COMMENT, text).with_span(self.span_from(start))
+    }
+
+    // Ignora um comentário `/* ... */`, que pode conter outros comentários
+    // de bloco aninhados. O cursor termina no '/' que fecha o comentário
+    fn skip_block_comment(&mut self) -> Result<(), LexError> {
+        let start = self.cursor.location();
+
+        // Consome o "/*" inicial
+        self.cursor.advance();
+        let mut depth = 1;
+
+        while depth > 0 {
+            self.cursor.advance();
+
+            if self.cursor.eof {
+                return Err(LexError::unterminated_comment(self.span_from(start)));
+            }
+
+            if self.cursor.current_char == '/' && self.cursor.next_is('*') {
+                self.cursor.advance();
+                depth += 1;
+            } else if self.cursor.current_char == '*' && self.cursor.next_is('/') {
+                self.cursor.advance();
+                depth -= 1;
+            }
+        }
+
+        Ok(())
     }
 
     pub fn read_identifier(&mut self) -> Token {
-        let mut identifier = String::new();
+        let mut identifier = String::from(self.cursor.current_char);
+        let start = self.cursor.location();
 
-        while !self.cursor.eof && (self.cursor.current_char.is_alphanumeric() || self.cursor.current_char == '_') {
+        while self.cursor.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.cursor.advance();
             identifier.push(self.cursor.current_char);
-            self.cursor.next();
         }
 
-        self.cursor.previous();
-
-        return Token::new(TokenType::IDENTIFIER, identifier);
+        Token::new(TokenType::IDENTIFIER, identifier).with_span(self.span_from(start))
     }
 
-    pub fn read_number(&mut self) -> Token {
+    // Lê um número inteiro ou de ponto flutuante, como `42`, `3.14` ou
+    // `1e-9`. O `_` pode ser usado como separador e é descartado
+    pub fn read_number(&mut self) -> Result<Token, LexError> {
+        if self.cursor.current_char == '0' {
+            let radix = match self.cursor.peek() {
+                Some('x') | Some('X') => Some(16),
+                Some('b') | Some('B') => Some(2),
+                Some('o') | Some('O') => Some(8),
+                _ => None,
+            };
+
+            if let Some(radix) = radix {
+                return self.read_radix_number(radix);
+            }
+        }
+
         let mut number = String::new();
+        let start = self.cursor.location();
+
+        let mut has_dot = false;
+        let mut has_exponent = false;
+
+        loop {
+            let current_char = self.cursor.current_char;
+
+            if current_char.is_ascii_digit() {
+                number.push(current_char);
+            } else if current_char == '.' {
+                // Só pode haver um ponto, antes do expoente e seguido de um dígito
+                if has_dot || has_exponent || !self.cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    return Err(self.malformed_number_error(start));
+                }
+
+                has_dot = true;
+                number.push(current_char);
+            } else if current_char == 'e' || current_char == 'E' {
+                if has_exponent {
+                    return Err(self.malformed_number_error(start));
+                }
+
+                has_exponent = true;
+                number.push(current_char);
+
+                // O expoente pode ter sinal e precisa de ao menos um dígito
+                if self.cursor.next_is('+') || self.cursor.next_is('-') {
+                    self.cursor.advance();
+                    number.push(self.cursor.current_char);
+                }
 
-        while !self.cursor.eof && (self.cursor.current_char.is_digit(10) || self.cursor.current_char == '.' || self.cursor.current_char == '_') {
-            if self.cursor.current_char != '_' {
-                number.push(self.cursor.current_char);
+                if !self.cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    return Err(self.malformed_number_error(start));
+                }
+            }
+
+            // O número continua enquanto o próximo caractere fizer parte dele
+            match self.cursor.peek() {
+                Some(c) if c.is_ascii_digit() || matches!(c, '_' | '.' | 'e' | 'E') => self.cursor.advance(),
+                _ => break,
             }
-            self.cursor.next();
         }
 
-        self.cursor.previous();
+        Ok(Token::new(TokenType::NUMBER, number).with_span(self.span_from(start)))
+    }
+
+    // Lê um inteiro hexadecimal (`0x1F`), binário (`0b1010`) ou octal
+    // (`0o755`). O token mantém o prefixo para que o parser saiba a base
+    fn read_radix_number(&mut self, radix: u32) -> Result<Token, LexError> {
+        let start = self.cursor.location();
 
-        return Token::new(TokenType::NUMBER, number);
+        // Consome o '0' e a letra da base
+        let mut number = String::from(self.cursor.current_char);
+        self.cursor.advance();
+        number.push(self.cursor.current_char);
+
+        let mut has_digits = false;
+
+        // O '_' é um separador; qualquer outro caractere alfanumérico precisa
+        // ser um dígito da base, então `0b102` é inválido
+        while self.cursor.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.cursor.advance();
+            let current_char = self.cursor.current_char;
+
+            if current_char.is_digit(radix) {
+                has_digits = true;
+                number.push(current_char);
+            } else if current_char != '_' {
+                return Err(self.malformed_number_error(start));
+            }
+        }
+
+        if !has_digits {
+            return Err(self.malformed_number_error(start));
+        }
+
+        Ok(Token::new(TokenType::NUMBER, number).with_span(self.span_from(start)))
+    }
+
+    // O erro cobre o literal inteiro, então o cursor avança até o fim dele
+    fn malformed_number_error(&mut self, start: Location) -> LexError {
+        while self.cursor.peek().is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '.')) {
+            self.cursor.advance();
+        }
+
+        let number = &self.cursor.input[start.offset..self.cursor.end_location().offset];
+        LexError::malformed_number(number.to_string(), self.span_from(start))
     }
 
-    pub fn read_string(&mut self) -> Result<Token, Box<dyn Error>> {
+    pub fn read_string(&mut self) -> Result<Token, LexError> {
         let mut string = String::new();
         let delim = self.cursor.current_char;
 
-        let initial_line = self.cursor.line;
-        let initial_column = self.cursor.column;
+        let start = self.cursor.location();
+
+        self.cursor.advance();
 
-        self.cursor.next();
+        // Um escape inválido não interrompe a leitura, para que o erro
+        // cubra a string inteira
+        let mut error = None;
 
         while !self.cursor.eof && self.cursor.current_char != delim {
-            string.push(self.cursor.current_char);
-            self.cursor.next();
+            if self.cursor.current_char == '\\' {
+                match self.read_escape() {
+                    Ok(escaped) => string.push(escaped),
+                    Err(escape_error) => { error.get_or_insert(escape_error); },
+                }
+            } else {
+                string.push(self.cursor.current_char);
+            }
+            self.cursor.advance();
         }
 
-        if self.cursor.current_char != delim {
-            return Err(format!(
-                "Erro: esperado fechamento da string ({}) iniciada na linha {} e coluna {} antes do fim do arquivo.",
-                delim, initial_line, initial_column
-            ).into());
+        // Uma string sem fechamento é o erro mais grave, mesmo que ela
+        // também tenha escapes inválidos
+        if self.cursor.eof {
+            return Err(self.unterminated_string_error(delim, start));
+        }
+
+        if let Some(error) = error {
+            return Err(error);
         }
 
-        Ok(Token::new(TokenType::STRING, string))
+        Ok(Token::new(TokenType::STRING, string).with_span(self.span_from(start)))
     }
 
-    pub fn tokenize(&mut self) -> Result<Vec<Token>, Box<dyn Error>> {
-        if self.cursor.eof {
-            return Ok(Vec::new());
-        }
+    fn unterminated_string_error(&self, delim: char, start: Location) -> LexError {
+        LexError::unterminated_string(delim, self.span_from(start))
+    }
+
+    // Lê uma f-string, como `f"Olá, {nome}!"`. O literal é dividido em um
+    // FSTRING_START, trechos de texto (FSTRING_TEXT), os tokens de cada
+    // expressão entre INTERPOLATION_START e INTERPOLATION_END, e um
+    // FSTRING_END. `{{` e `}}` representam chaves literais no texto
+    pub fn read_fstring(&mut self) -> Result<(), LexError> {
+        let start = self.cursor.location();
+
+        // Consome o 'f' e o delimitador
+        self.cursor.advance();
+        let delim = self.cursor.current_char;
+        self.pending.push_back(Token::new(TokenType::FSTRING_START, format!("f{}", delim)).with_span(self.span_from(start)));
 
-        let mut tokens: Vec<Token> = Vec::new();
-        let mut current_cursor = self.cursor.next_skip_whitespace();
+        let mut text = String::new();
+        let mut text_start: Option<Location> = None;
 
-        while !current_cursor.eof {
-            match current_cursor.current_char {
-                '=' => {
-                    if current_cursor.previous_is('+') || current_cursor.previous_is('-') || current_cursor.previous_is('*') || current_cursor.previous_is('/') {
-                        let mut previous_token = tokens.pop().unwrap();
-                        previous_token.value.push(current_cursor.current_char);
+        // Assim como em `read_string`, o primeiro erro é guardado e a
+        // leitura segue até o fim da f-string
+        let mut error = None;
 
-                        tokens.push(Token::new(TokenType::ASSIGNMENT, previous_token.value));
-                    } else {
-                        self.push_token(TokenType::ASSIGNMENT, current_cursor.current_char, &mut tokens);
+        loop {
+            self.cursor.advance();
+
+            if self.cursor.eof {
+                return Err(self.unterminated_string_error(delim, start));
+            }
+
+            let current_char = self.cursor.current_char;
+            let location = self.cursor.location();
+
+            // Ao encontrar o delimitador ou uma interpolação, o texto acumulado vira um token
+            if current_char == delim || (current_char == '{' && !self.cursor.next_is('{')) {
+                if let Some(text_start) = text_start.take() {
+                    let span = Span::new(self.file_id, text_start, location);
+                    self.pending.push_back(Token::new(TokenType::FSTRING_TEXT, std::mem::take(&mut text)).with_span(span));
+                }
+
+                if current_char == delim {
+                    self.push_token(TokenType::FSTRING_END, current_char);
+                    return error.map_or(Ok(()), Err);
+                }
+
+                if let Err(interpolation_error) = self.read_interpolation(delim, start) {
+                    if self.cursor.eof {
+                        return Err(interpolation_error);
                     }
+                    error.get_or_insert(interpolation_error);
+                }
+                continue;
+            }
+
+            text_start.get_or_insert(location);
+
+            match current_char {
+                '\\' => match self.read_escape() {
+                    Ok(escaped) => text.push(escaped),
+                    Err(escape_error) => { error.get_or_insert(escape_error); },
+                },
+                '{' | '}' if self.cursor.next_is(current_char) => {
+                    self.cursor.advance();
+                    text.push(current_char);
                 },
-                '+' => self.push_token(TokenType::OPERATOR, current_cursor.current_char, &mut tokens),
-                '-' => self.push_token(TokenType::OPERATOR, current_cursor.current_char, &mut tokens),
-                '*' => self.push_token(TokenType::OPERATOR, current_cursor.current_char, &mut tokens),
-                '/' => self.push_token(TokenType::OPERATOR, current_cursor.current_char, &mut tokens),
-                '(' => self.push_token(TokenType::LPAREN, current_cursor.current_char, &mut tokens),
-                ')' => self.push_token(TokenType::RPAREN, current_cursor.current_char, &mut tokens),
-                '{' => self.push_token(TokenType::LBRACKET, current_cursor.current_char, &mut tokens),
-                '}' => self.push_token(TokenType::RBRACKET, current_cursor.current_char, &mut tokens),
-                ',' => self.push_token(TokenType::COMMA, current_cursor.current_char, &mut tokens),
-                ';' => self.push_token(TokenType::SEMICOLON, current_cursor.current_char, &mut tokens),
-                '<' => self.push_token(TokenType::OPERATOR, current_cursor.current_char, &mut tokens),
-                '>' => self.push_token(TokenType::OPERATOR, current_cursor.current_char, &mut tokens),
-                _ => {
-                    if current_cursor.current_char.is_alphabetic() || current_cursor.current_char == '_' {
-                        let mut token = self.read_identifier();
-    
-                        if self.is_keyword(token.value.clone()) {
-                            token._type = TokenType::KEYWORD;
-                        }
-    
-                        tokens.push(token);
-                    } else if current_cursor.current_char.is_digit(10) {
-                        tokens.push(self.read_number());
-                    } else if current_cursor.current_char == '"' || current_cursor.current_char == '\'' {
-                        let result = self.read_string();
-                        
-                        if result.is_err() {
-                            return Err(result.unwrap_err());
-                        }
-    
-                        tokens.push(result.unwrap());
-                    } else {
-                        return Err(format!(
-                            "Caractere inválido encontrado '{}' na linha {} e coluna {}",
-                            current_cursor.current_char,
-                            current_cursor.line,
-                            current_cursor.column
-                        ).into());
+                '}' => {
+                    let span = self.span_from(location);
+                    error.get_or_insert(LexError::unmatched_brace(span));
+                },
+                _ => text.push(current_char),
+            }
+        }
+    }
+
+    // Lê os tokens da expressão de uma interpolação `{...}` dentro de uma
+    // f-string. O cursor começa no '{' e termina no '}' correspondente
+    fn read_interpolation(&mut self, delim: char, fstring_start: Location) -> Result<(), LexError> {
+        self.push_token(TokenType::INTERPOLATION_START, '{');
+
+        // Chaves abertas dentro da própria expressão
+        let mut depth = 0;
+        let mut error = None;
+
+        loop {
+            self.cursor.advance_skip_whitespace();
+
+            if self.cursor.eof {
+                return Err(self.unterminated_string_error(delim, fstring_start));
+            }
+
+            match self.cursor.current_char {
+                '}' if depth == 0 => {
+                    self.push_token(TokenType::INTERPOLATION_END, '}');
+                    return error.map_or(Ok(()), Err);
+                },
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {},
+            }
+
+            if let Err(token_error) = self.read_token() {
+                error.get_or_insert(token_error);
+            }
+        }
+    }
+
+    // Lê uma sequência de escape iniciada no '\\' atual e retorna o caractere
+    // que ela representa. O cursor termina no último caractere da sequência
+    fn read_escape(&mut self) -> Result<char, LexError> {
+        let start = self.cursor.location();
+        self.cursor.advance();
+
+        // Uma `\` no fim do arquivo não inicia um escape: quem chamou
+        // detecta o fim do arquivo e informa a string sem fechamento
+        if self.cursor.eof {
+            return Ok('\\');
+        }
+
+        let escaped_char = self.cursor.current_char;
+        let escaped = match escaped_char {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '\\' => '\\',
+            '"' => '"',
+            '\'' => '\'',
+            'u' if self.cursor.next_is('{') => {
+                self.cursor.advance();
+
+                // \u{...} aceita de 1 a 6 dígitos hexadecimais
+                let mut code = String::new();
+                while self.cursor.peek().is_some_and(|c| c.is_ascii_hexdigit()) && code.len() < 6 {
+                    self.cursor.advance();
+                    code.push(self.cursor.current_char);
+                }
+
+                if code.is_empty() || !self.cursor.next_is('}') {
+                    return Err(self.invalid_escape_error(start));
+                }
+                self.cursor.advance();
+
+                u32::from_str_radix(&code, 16).ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.invalid_escape_error(start))?
+            },
+            _ => return Err(self.invalid_escape_error(start)),
+        };
+
+        Ok(escaped)
+    }
+
+    fn invalid_escape_error(&self, start: Location) -> LexError {
+        let sequence = &self.cursor.input[start.offset..self.cursor.end_location().offset];
+        LexError::invalid_escape(sequence.to_string(), self.span_from(start))
+    }
+
+    pub fn tokenize(&mut self) -> Result<Vec<Token>, LexError> {
+        self.collect()
+    }
+
+    // Lê o operador mais longo que começa no caractere atual
+    fn read_operator(&mut self) -> Result<(), LexError> {
+        let remaining = self.cursor.remaining();
+        let Some((value, _type)) = OPERATORS.iter().find(|(value, _)| remaining.starts_with(value)) else {
+            return Err(self.invalid_char_error());
+        };
+
+        self.push_multi_char_token(_type.clone(), value);
+        Ok(())
+    }
+
+    // `div` é o operador de divisão inteira, já que `//` inicia um
+    // comentário. Seguido de `=` vira a atribuição composta `div=`, mas
+    // `div==` continua sendo `div` seguido de `==`
+    fn read_int_divide(&mut self, token: Token) -> Token {
+        let next = &self.cursor.remaining()[self.cursor.current_char.len_utf8()..];
+
+        if next.starts_with('=') && !next.starts_with("==") {
+            self.cursor.advance();
+            let span = self.span_from(token.span.start_location());
+            return Token::new(TokenType::DIV_EQUAL, "div=".to_string()).with_span(span);
+        }
+
+        Token::new(TokenType::DIV, token.value).with_span(token.span)
+    }
+
+    // Lê o próximo token sob demanda. No fim do código produz um único token
+    // EOF e depois retorna `None`; depois de um erro, não lê mais nada. No
+    // modo de recuperação, o trecho inválido vira um token ERROR e a leitura
+    // continua
+    pub fn next_token(&mut self) -> Option<Result<Token, LexError>> {
+        // Comentários não produzem tokens, então pode ser preciso ler mais de um trecho
+        while self.pending.is_empty() {
+            if self.finished {
+                return None;
+            }
+
+            self.cursor.advance_skip_whitespace();
+
+            if self.cursor.eof {
+                // O EOF fica no fim real do código, depois dos espaços e
+                // comentários finais
+                let location = self.cursor.location();
+                let span = Span::new(self.file_id, location, location);
+                self.finished = true;
+                return Some(Ok(Token::new(TokenType::EOF, String::new()).with_span(span)));
+            }
+
+            let start = self.cursor.location();
+
+            if let Err(error) = self.read_token() {
+                // Descarta os tokens já lidos do trecho inválido, como o início de uma f-string
+                self.pending.clear();
+
+                if !self.recover {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+
+                let value = &self.cursor.input[start.offset..self.cursor.end_location().offset];
+                let token = Token::new(TokenType::ERROR, value.to_string()).with_span(self.span_from(start));
+
+                self.pending.push_back(token);
+                self.errors.push(error);
+            }
+        }
+
+        self.pending.pop_front().map(Ok)
+    }
+
+    // Lê o token que começa no caractere atual do cursor e o adiciona aos
+    // pendentes. Em caso de erro, o cursor termina no último caractere do
+    // trecho inválido
+    fn read_token(&mut self) -> Result<(), LexError> {
+        let current_char = self.cursor.current_char;
+
+        match current_char {
+            '=' | '+' | '-' | '*' | '%' | '<' | '>' | '!' | '&' | '|' | '^' | '~' => self.read_operator()?,
+            '/' => {
+                // `///` é um comentário de documentação, mas `////` é um comentário comum
+                if self.cursor.remaining().starts_with("///") && !self.cursor.remaining().starts_with("////") {
+                    let token = self.read_doc_comment();
+                    self.pending.push_back(token);
+                } else if self.cursor.next_is('/') {
+                    self.skip_line_comment();
+                } else if self.cursor.next_is('*') {
+                    self.skip_block_comment()?;
+                } else {
+                    self.read_operator()?;
+                }
+            },
+            '(' => self.push_token(TokenType::LPAREN, current_char),
+            ')' => self.push_token(TokenType::RPAREN, current_char),
+            '{' => self.push_token(TokenType::LBRACKET, current_char),
+            '}' => self.push_token(TokenType::RBRACKET, current_char),
+            ',' => self.push_token(TokenType::COMMA, current_char),
+            ';' => self.push_token(TokenType::SEMICOLON, current_char),
+            _ => {
+                if current_char == 'f' && (self.cursor.next_is('"') || self.cursor.next_is('\'')) {
+                    self.read_fstring()?;
+                } else if current_char.is_alphabetic() || current_char == '_' {
+                    let mut token = self.read_identifier();
+
+                    if token.value == "div" {
+                        token = self.read_int_divide(token);
+                    } else if self.is_keyword(token.value.clone()) {
+                        token._type = TokenType::KEYWORD;
                     }
+
+                    self.pending.push_back(token);
+                } else if current_char.is_ascii_digit() {
+                    let token = self.read_number()?;
+                    self.pending.push_back(token);
+                } else if current_char == '"' || current_char == '\'' {
+                    let token = self.read_string()?;
+                    self.pending.push_back(token);
+                } else {
+                    return Err(self.invalid_char_error());
                 }
             }
-    
-            current_cursor = self.cursor.next_skip_whitespace();
         }
 
-        Ok(tokens)
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token()
+    }
+}
@@ -1,112 +1,112 @@
+use std::{iter::Peekable, str::CharIndices};
+
+use crate::span::Location;
+
+// Percorre o código-fonte caractere por caractere. O cursor guarda apenas o
+// deslocamento em bytes do caractere atual e um iterador sobre os próximos,
+// então avançar e espiar o próximo caractere custam O(1)
 #[derive(Clone, Debug)]
-pub struct LexerCursor {
-    pub input: String,
+pub struct LexerCursor<'a> {
+    pub input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
     pub current_char: char,
-    pub position: usize,
+    // Deslocamento em bytes do caractere atual
+    pub offset: usize,
     pub line: usize,
     pub column: usize,
+    // Indica se o primeiro caractere já foi lido
+    started: bool,
     pub eof: bool
 }
 
-impl LexerCursor {
-    pub fn new(input: String) -> Self {
+impl<'a> LexerCursor<'a> {
+    pub fn new(input: &'a str) -> Self {
         Self {
-            input: input.clone(),
+            input,
+            chars: input.char_indices().peekable(),
             current_char: '\0',
-            position: 0,
-            line: 0,
+            offset: 0,
+            line: 1,
             column: 0,
-            eof: input.is_empty()
+            started: false,
+            eof: false
         }
     }
 
     pub fn reset_cursor(&mut self) {
-        self.position = 0;
-        self.line = 0;
-        self.column = 0;
-        self.eof = self.input.is_empty();
-        self.current_char = '\0';
+        *self = Self::new(self.input);
+    }
+
+    // Posição do caractere atual no código-fonte
+    pub fn location(&self) -> Location {
+        Location {
+            offset: self.offset,
+            line: self.line,
+            column: self.column,
+        }
     }
 
-    pub fn next(&mut self) -> Self {
+    // Posição logo após o caractere atual
+    pub fn end_location(&self) -> Location {
         if self.eof {
-            return self.clone();
+            return self.location();
         }
-    
-        if self.line == 0 {
-            self.line += 1;
-        } else {
-            self.position += 1;
+
+        Location {
+            offset: self.offset + self.current_char.len_utf8(),
+            line: self.line,
+            column: self.column + 1,
         }
-    
-        // Usa a contagem de caracteres para evitar inconsistências com caracteres multibyte
-        let total_chars = self.input.chars().count();
-        if self.position >= total_chars {
-            self.eof = true;
-            // Garante que o índice permaneça em um valor válido
-            if self.position > 0 {
-                self.position -= 1;
-            }
-            return self.clone();
+    }
+
+    // Avança para o próximo caractere. Depois do último, o cursor fica
+    // logo após o fim do código, com `eof` verdadeiro
+    pub fn advance(&mut self) {
+        if self.eof {
+            return;
         }
-    
-        self.column += 1;
-        self.current_char = self.input.chars().nth(self.position).unwrap();
-    
-        if self.current_char == '\n' {
-            self.column = 1;
+
+        // A quebra de linha pertence à linha que ela encerra
+        if self.started && self.current_char == '\n' {
             self.line += 1;
+            self.column = 0;
         }
-    
-        self.clone()
-    }    
 
-    pub fn next_skip_whitespace(&mut self) -> Self {
-        let total_chars = self.input.chars().count();
-        while self.position + 1 < total_chars {
-            if let Some(next_char) = self.input.chars().nth(self.position + 1) {
-                if !next_char.is_whitespace() {
-                    break;
-                }
-            } else {
-                break;
+        self.started = true;
+        self.column += 1;
+
+        match self.chars.next() {
+            Some((offset, current_char)) => {
+                self.offset = offset;
+                self.current_char = current_char;
+            },
+            None => {
+                self.offset = self.input.len();
+                self.current_char = '\0';
+                self.eof = true;
             }
-            self.next();
         }
-        self.next()
-    }    
+    }
 
-    pub fn previous(&mut self) -> Self {
-        if self.position == 0 {
-            return self.clone();
-        }
-    
-        self.position -= 1;
-        self.current_char = self.input.chars().nth(self.position).unwrap();
-        
-        if self.current_char == '\n' {
-            self.line -= 1;
-            self.column = self.input[..self.position]
-                .chars()
-                .rev()
-                .take_while(|&c| c != '\n')
-                .count();
-        } else if self.column > 1 {
-            self.column -= 1;
-        }
-        
-        if self.eof {
-            self.eof = false;
+    // Avança até o próximo caractere que não seja um espaço em branco
+    pub fn advance_skip_whitespace(&mut self) {
+        self.advance();
+        while !self.eof && self.current_char.is_whitespace() {
+            self.advance();
         }
+    }
 
-        self.clone()
+    // Retorna o próximo caractere sem avançar o cursor
+    pub fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, next_char)| next_char)
     }
 
-    pub fn next_is(&self, expected: char) -> bool {
-        self.position + 1 < self.input.len() && self.input.chars().nth(self.position + 1) == Some(expected)
+    // Trecho do código a partir do caractere atual
+    pub fn remaining(&self) -> &'a str {
+        &self.input[self.offset..]
     }
 
-    pub fn previous_is(&self, expected: char) -> bool {
-        self.position > 0 && self.input.chars().nth(self.position - 1) == Some(expected)
+    pub fn next_is(&mut self, expected: char) -> bool {
+        self.peek() == Some(expected)
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,12 @@
+pub mod span;
 pub mod token;
 
 pub mod lexer_cursor;
+pub mod lex_error;
 pub mod lexer;
 
+pub mod operator;
 pub mod ast_node;
+pub mod parse_error;
+pub mod precedence;
 pub mod ast;
\ No newline at end of file
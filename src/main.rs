@@ -1,9 +1,14 @@
+pub mod span;
 pub mod token;
 
 pub mod lexer_cursor;
+pub mod lex_error;
 pub mod lexer;
 
+pub mod operator;
 pub mod ast_node;
+pub mod parse_error;
+pub mod precedence;
 pub mod ast;
 
 fn main() {
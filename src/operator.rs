@@ -0,0 +1,138 @@
+use std::fmt;
+
+// Operadores binários aritméticos, bit a bit e de comparação
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    IntDivide,
+    Modulo,
+    Power,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+    Equal,
+    NotEqual,
+    Less,
+    Greater,
+    LessEqual,
+    GreaterEqual,
+}
+
+// Operadores lógicos com curto-circuito
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogicalOperator {
+    And,
+    Or,
+}
+
+// Operadores prefixos
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOperator {
+    Negate,
+    Plus,
+    Not,
+    BitNot,
+}
+
+// Atribuição simples ou composta
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssignOperator {
+    Assign,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    IntDivide,
+    Modulo,
+    Power,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl AssignOperator {
+    // Operação aplicada antes de atribuir: `a += b` equivale a `a = a + b`.
+    // A atribuição simples não tem operação
+    pub fn binary_operator(self) -> Option<BinaryOperator> {
+        match self {
+            AssignOperator::Assign => None,
+            AssignOperator::Add => Some(BinaryOperator::Add),
+            AssignOperator::Subtract => Some(BinaryOperator::Subtract),
+            AssignOperator::Multiply => Some(BinaryOperator::Multiply),
+            AssignOperator::Divide => Some(BinaryOperator::Divide),
+            AssignOperator::IntDivide => Some(BinaryOperator::IntDivide),
+            AssignOperator::Modulo => Some(BinaryOperator::Modulo),
+            AssignOperator::Power => Some(BinaryOperator::Power),
+            AssignOperator::BitAnd => Some(BinaryOperator::BitAnd),
+            AssignOperator::BitOr => Some(BinaryOperator::BitOr),
+            AssignOperator::BitXor => Some(BinaryOperator::BitXor),
+            AssignOperator::ShiftLeft => Some(BinaryOperator::ShiftLeft),
+            AssignOperator::ShiftRight => Some(BinaryOperator::ShiftRight),
+        }
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::IntDivide => "div",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Power => "**",
+            BinaryOperator::BitAnd => "&",
+            BinaryOperator::BitOr => "|",
+            BinaryOperator::BitXor => "^",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::LessEqual => "<=",
+            BinaryOperator::GreaterEqual => ">=",
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+impl fmt::Display for LogicalOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogicalOperator::And => write!(f, "&&"),
+            LogicalOperator::Or => write!(f, "||"),
+        }
+    }
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            UnaryOperator::Negate => "-",
+            UnaryOperator::Plus => "+",
+            UnaryOperator::Not => "!",
+            UnaryOperator::BitNot => "~",
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+impl fmt::Display for AssignOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.binary_operator() {
+            Some(operator) => write!(f, "{}=", operator),
+            None => write!(f, "="),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::{error::Error, fmt};
+
+use crate::{span::Span, token::{Token, TokenType}};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    // O token encontrado não é nenhum dos esperados
+    UnexpectedToken { expected: Vec<TokenType> },
+    // O literal numérico não pôde ser convertido
+    InvalidNumber,
+    // O literal inteiro não cabe em um i64
+    IntegerOverflow,
+    // O literal de ponto flutuante não cabe em um f64
+    FloatOverflow,
+    // O lado esquerdo de uma atribuição não é uma variável
+    InvalidAssignmentTarget,
+    // `pare` ou `continue` usado fora do corpo de um laço
+    OutsideLoop,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub found: Token,
+}
+
+impl ParseError {
+    pub fn unexpected_token(expected: Vec<TokenType>, found: Token) -> Self {
+        Self {
+            kind: ParseErrorKind::UnexpectedToken { expected },
+            found,
+        }
+    }
+
+    pub fn invalid_number(found: Token) -> Self {
+        Self {
+            kind: ParseErrorKind::InvalidNumber,
+            found,
+        }
+    }
+
+    pub fn integer_overflow(found: Token) -> Self {
+        Self {
+            kind: ParseErrorKind::IntegerOverflow,
+            found,
+        }
+    }
+
+    pub fn float_overflow(found: Token) -> Self {
+        Self {
+            kind: ParseErrorKind::FloatOverflow,
+            found,
+        }
+    }
+
+    pub fn invalid_assignment_target(found: Token) -> Self {
+        Self {
+            kind: ParseErrorKind::InvalidAssignmentTarget,
+            found,
+        }
+    }
+
+    pub fn outside_loop(found: Token) -> Self {
+        Self {
+            kind: ParseErrorKind::OutsideLoop,
+            found,
+        }
+    }
+
+    // Tipos de token que eram esperados no lugar do token encontrado
+    pub fn expected(&self) -> &[TokenType] {
+        match &self.kind {
+            ParseErrorKind::UnexpectedToken { expected } => expected,
+            _ => &[],
+        }
+    }
+
+    // Localização do erro no código-fonte
+    pub fn span(&self) -> &Span {
+        &self.found.span
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = if self.found._type == TokenType::EOF {
+            "fim do arquivo".to_string()
+        } else {
+            format!("'{}'", self.found.value)
+        };
+
+        match &self.kind {
+            ParseErrorKind::UnexpectedToken { expected } => {
+                let expected = expected.iter()
+                    .map(|_type| format!("{:?}", _type))
+                    .collect::<Vec<_>>()
+                    .join(" ou ");
+
+                if self.found._type == TokenType::EOF {
+                    write!(f, "Erro: fim do arquivo inesperado, esperava {}", expected)?;
+                } else {
+                    write!(f, "Erro: esperava {}, encontrou {}", expected, found)?;
+                }
+            },
+            ParseErrorKind::InvalidNumber => {
+                write!(f, "Erro: número inválido {}", found)?;
+            },
+            ParseErrorKind::IntegerOverflow => {
+                write!(f, "Erro: o número {} é grande demais para um inteiro", found)?;
+            },
+            ParseErrorKind::FloatOverflow => {
+                write!(f, "Erro: o número {} é grande demais para um número de ponto flutuante", found)?;
+            },
+            ParseErrorKind::InvalidAssignmentTarget => {
+                write!(f, "Erro: o lado esquerdo de {} não é uma variável", found)?;
+            },
+            ParseErrorKind::OutsideLoop => {
+                write!(f, "Erro: {} só pode ser usado dentro de um laço", found)?;
+            },
+        }
+
+        write!(f, " na linha {} e coluna {}", self.span().start_line, self.span().start_column)
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,127 @@
+use crate::{operator::{AssignOperator, BinaryOperator, LogicalOperator, UnaryOperator}, token::{Token, TokenType}};
+
+// Níveis de precedência dos operadores, do mais fraco ao mais forte
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precedence {
+    Lowest,
+    Assignment,
+    Or,
+    And,
+    Equality,
+    Comparison,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
+    Additive,
+    Multiplicative,
+    Unary,
+    // `**` se liga mais forte que os prefixos, então `-2 ** 2` é `-(2 ** 2)`
+    Power,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+// Nó do AST criado pelo operador, com o operador tipado que ele guarda
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InfixKind {
+    Assignment(AssignOperator),
+    Logical(LogicalOperator),
+    Binary(BinaryOperator),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InfixOperator {
+    pub precedence: Precedence,
+    pub associativity: Associativity,
+    pub kind: InfixKind,
+}
+
+impl InfixOperator {
+    const fn new(precedence: Precedence, associativity: Associativity, kind: InfixKind) -> Self {
+        Self { precedence, associativity, kind }
+    }
+
+    // Poderes de ligação (esquerdo, direito) usados pelo parser. O operando
+    // à direita só continua a expressão se o próximo operador tiver poder
+    // esquerdo maior ou igual ao poder direito deste, então um poder direito
+    // menor que o esquerdo torna o operador associativo à direita
+    pub fn binding_power(&self) -> (u8, u8) {
+        let power = self.precedence.binding_power();
+
+        match self.associativity {
+            Associativity::Left => (power, power + 1),
+            Associativity::Right => (power + 1, power),
+        }
+    }
+}
+
+impl Precedence {
+    pub fn binding_power(self) -> u8 {
+        self as u8 * 2
+    }
+}
+
+// Tabela de operadores binários. Adicionar um operador é só adicionar uma linha
+const INFIX_OPERATORS: &[(TokenType, InfixOperator)] = &[
+    (TokenType::EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Assign))),
+    (TokenType::PLUS_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Add))),
+    (TokenType::MINUS_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Subtract))),
+    (TokenType::STAR_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Multiply))),
+    (TokenType::SLASH_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Divide))),
+    (TokenType::PERCENT_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Modulo))),
+    (TokenType::DIV_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::IntDivide))),
+    (TokenType::STAR_STAR_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::Power))),
+    (TokenType::AMPERSAND_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::BitAnd))),
+    (TokenType::PIPE_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::BitOr))),
+    (TokenType::CARET_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::BitXor))),
+    (TokenType::LESS_LESS_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::ShiftLeft))),
+    (TokenType::GREATER_GREATER_EQUAL, InfixOperator::new(Precedence::Assignment, Associativity::Right, InfixKind::Assignment(AssignOperator::ShiftRight))),
+    (TokenType::PIPE_PIPE, InfixOperator::new(Precedence::Or, Associativity::Left, InfixKind::Logical(LogicalOperator::Or))),
+    (TokenType::AMPERSAND_AMPERSAND, InfixOperator::new(Precedence::And, Associativity::Left, InfixKind::Logical(LogicalOperator::And))),
+    (TokenType::EQUAL_EQUAL, InfixOperator::new(Precedence::Equality, Associativity::Left, InfixKind::Binary(BinaryOperator::Equal))),
+    (TokenType::BANG_EQUAL, InfixOperator::new(Precedence::Equality, Associativity::Left, InfixKind::Binary(BinaryOperator::NotEqual))),
+    (TokenType::LESS, InfixOperator::new(Precedence::Comparison, Associativity::Left, InfixKind::Binary(BinaryOperator::Less))),
+    (TokenType::GREATER, InfixOperator::new(Precedence::Comparison, Associativity::Left, InfixKind::Binary(BinaryOperator::Greater))),
+    (TokenType::LESS_EQUAL, InfixOperator::new(Precedence::Comparison, Associativity::Left, InfixKind::Binary(BinaryOperator::LessEqual))),
+    (TokenType::GREATER_EQUAL, InfixOperator::new(Precedence::Comparison, Associativity::Left, InfixKind::Binary(BinaryOperator::GreaterEqual))),
+    (TokenType::PIPE, InfixOperator::new(Precedence::BitOr, Associativity::Left, InfixKind::Binary(BinaryOperator::BitOr))),
+    (TokenType::CARET, InfixOperator::new(Precedence::BitXor, Associativity::Left, InfixKind::Binary(BinaryOperator::BitXor))),
+    (TokenType::AMPERSAND, InfixOperator::new(Precedence::BitAnd, Associativity::Left, InfixKind::Binary(BinaryOperator::BitAnd))),
+    (TokenType::LESS_LESS, InfixOperator::new(Precedence::Shift, Associativity::Left, InfixKind::Binary(BinaryOperator::ShiftLeft))),
+    (TokenType::GREATER_GREATER, InfixOperator::new(Precedence::Shift, Associativity::Left, InfixKind::Binary(BinaryOperator::ShiftRight))),
+    (TokenType::PLUS, InfixOperator::new(Precedence::Additive, Associativity::Left, InfixKind::Binary(BinaryOperator::Add))),
+    (TokenType::MINUS, InfixOperator::new(Precedence::Additive, Associativity::Left, InfixKind::Binary(BinaryOperator::Subtract))),
+    (TokenType::STAR, InfixOperator::new(Precedence::Multiplicative, Associativity::Left, InfixKind::Binary(BinaryOperator::Multiply))),
+    (TokenType::SLASH, InfixOperator::new(Precedence::Multiplicative, Associativity::Left, InfixKind::Binary(BinaryOperator::Divide))),
+    (TokenType::PERCENT, InfixOperator::new(Precedence::Multiplicative, Associativity::Left, InfixKind::Binary(BinaryOperator::Modulo))),
+    (TokenType::DIV, InfixOperator::new(Precedence::Multiplicative, Associativity::Left, InfixKind::Binary(BinaryOperator::IntDivide))),
+    (TokenType::STAR_STAR, InfixOperator::new(Precedence::Power, Associativity::Right, InfixKind::Binary(BinaryOperator::Power))),
+];
+
+// Operadores prefixos; todos têm a precedência `Unary`
+const PREFIX_OPERATORS: &[(TokenType, UnaryOperator)] = &[
+    (TokenType::MINUS, UnaryOperator::Negate),
+    (TokenType::PLUS, UnaryOperator::Plus),
+    (TokenType::BANG, UnaryOperator::Not),
+    (TokenType::TILDE, UnaryOperator::BitNot),
+];
+
+// Busca o operador binário representado pelo token
+pub fn infix_operator(token: &Token) -> Option<InfixOperator> {
+    INFIX_OPERATORS.iter()
+        .find(|(_type, _)| token._type == *_type)
+        .map(|&(_, operator)| operator)
+}
+
+// Busca o operador prefixo representado pelo token. O operando de todos os
+// prefixos é lido com o poder de ligação de `Precedence::Unary`
+pub fn prefix_operator(token: &Token) -> Option<UnaryOperator> {
+    PREFIX_OPERATORS.iter()
+        .find(|(_type, _)| token._type == *_type)
+        .map(|&(_, operator)| operator)
+}
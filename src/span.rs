@@ -0,0 +1,47 @@
+// Posição de um caractere no código-fonte
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Location {
+    // Deslocamento em bytes a partir do início do arquivo
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+// Trecho do código-fonte ocupado por um token
+//
+// `start` e `end` são deslocamentos em bytes (`end` é exclusivo), e
+// `end_line`/`end_column` apontam para a posição logo após o último caractere
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    pub file_id: usize,
+    pub start: usize,
+    pub end: usize,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl Span {
+    pub fn new(file_id: usize, start: Location, end: Location) -> Self {
+        Self {
+            file_id,
+            start: start.offset,
+            end: end.offset,
+            start_line: start.line,
+            start_column: start.column,
+            end_line: end.line,
+            end_column: end.column,
+        }
+    }
+
+    // Início do trecho
+    pub fn start_location(&self) -> Location {
+        Location { offset: self.start, line: self.start_line, column: self.start_column }
+    }
+
+    // Fim (exclusivo) do trecho
+    pub fn end_location(&self) -> Location {
+        Location { offset: self.end, line: self.end_line, column: self.end_column }
+    }
+}
@@ -1,31 +1,87 @@
+use crate::span::Span;
+
+#[allow(non_camel_case_types)]
 #[derive(Clone, PartialEq, Debug)]
 pub enum TokenType {
     KEYWORD,
     IDENTIFIER,
     NUMBER,
     STRING,
-    ASSIGNMENT,
-    OPERATOR,
+    // Partes de uma f-string: f"texto {expressão} texto"
+    FSTRING_START,
+    FSTRING_TEXT,
+    INTERPOLATION_START,
+    INTERPOLATION_END,
+    FSTRING_END,
+    // Operadores
+    PLUS,
+    MINUS,
+    STAR,
+    SLASH,
+    PERCENT,
+    // Divisão inteira `div`
+    DIV,
+    STAR_STAR,
+    AMPERSAND,
+    PIPE,
+    CARET,
+    TILDE,
+    BANG,
+    LESS_LESS,
+    GREATER_GREATER,
+    EQUAL_EQUAL,
+    BANG_EQUAL,
+    LESS,
+    GREATER,
+    LESS_EQUAL,
+    GREATER_EQUAL,
+    AMPERSAND_AMPERSAND,
+    PIPE_PIPE,
+    // Atribuição simples e compostas
+    EQUAL,
+    PLUS_EQUAL,
+    MINUS_EQUAL,
+    STAR_EQUAL,
+    SLASH_EQUAL,
+    PERCENT_EQUAL,
+    DIV_EQUAL,
+    STAR_STAR_EQUAL,
+    AMPERSAND_EQUAL,
+    PIPE_EQUAL,
+    CARET_EQUAL,
+    LESS_LESS_EQUAL,
+    GREATER_GREATER_EQUAL,
     SEMICOLON,
     COMMA,
     LPAREN,
     RPAREN,
     LBRACKET,
     RBRACKET,
+    // Comentário de documentação `/// ...`
+    DOC_COMMENT,
+    // Trecho inválido, produzido apenas quando o lexer se recupera de erros
+    ERROR,
     EOF
 }
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Token {
     pub _type: TokenType,
-    pub value: String
+    pub value: String,
+    pub span: Span
 }
 
 impl Token {
     pub fn new(_type: TokenType, value: String) -> Self {
         Self {
             _type: _type.clone(),
-            value: value.clone()
+            value: value.clone(),
+            span: Span::default()
         }
     }
-}
\ No newline at end of file
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+}
@@ -1,11 +1,11 @@
-use interpreter::{ast::Parser, ast_node::ASTNode, token::{Token, TokenType}};
+use interpreter::{ast::Parser, ast_node::ASTNode, lexer::Lexer, operator::{AssignOperator, BinaryOperator, LogicalOperator, UnaryOperator}, parse_error::{ParseError, ParseErrorKind}, precedence::{infix_operator, prefix_operator, Associativity, InfixKind, Precedence}, token::{Token, TokenType}};
 
 #[test]
 fn test_ast_1_plus_2() {
     // Cria os tokens correspondentes à expressão "1 + 2"
     let tokens = vec![
         Token::new(TokenType::NUMBER, "1".to_string()),
-        Token::new(TokenType::OPERATOR, "+".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
         Token::new(TokenType::NUMBER, "2".to_string()),
         // Opcional: token de fim de arquivo
         Token::new(TokenType::EOF, "".to_string()),
@@ -13,7 +13,7 @@ fn test_ast_1_plus_2() {
 
     // Cria o parser e processa os tokens
     let mut parser = Parser::new(tokens);
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
 
     // Valida o AST gerado
     match ast {
@@ -26,8 +26,7 @@ fn test_ast_1_plus_2() {
             }
 
             // Verifica se o operador é '+'
-            assert_eq!(op._type, TokenType::OPERATOR);
-            assert_eq!(op.value, "+");
+            assert_eq!(op, BinaryOperator::Add);
 
             // Verifica se o nó à direita é o número 2
             if let ASTNode::Number(value) = *right {
@@ -45,7 +44,7 @@ fn test_ast_num1_plus_num2() {
     // Cria os tokens correspondentes à expressão "num1 + num2"
     let tokens = vec![
         Token::new(TokenType::IDENTIFIER, "num1".to_string()),
-        Token::new(TokenType::OPERATOR, "+".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
         Token::new(TokenType::IDENTIFIER, "num2".to_string()),
         // Opcional: token de fim de arquivo
         Token::new(TokenType::EOF, "".to_string()),
@@ -53,7 +52,7 @@ fn test_ast_num1_plus_num2() {
 
     // Cria o parser e processa os tokens
     let mut parser = Parser::new(tokens);
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
 
     // Valida o AST gerado
     match ast {
@@ -66,8 +65,7 @@ fn test_ast_num1_plus_num2() {
             }
 
             // Verifica se o operador é '+'
-            assert_eq!(op._type, TokenType::OPERATOR);
-            assert_eq!(op.value, "+");
+            assert_eq!(op, BinaryOperator::Add);
 
             // Verifica se o nó à direita é o número 2
             if let ASTNode::Identifier(value) = *right {
@@ -86,7 +84,7 @@ fn test_ast_variable_declaration() {
     let tokens = vec![
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "nome".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "10".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
         // Opcional: token de fim de arquivo
@@ -95,11 +93,11 @@ fn test_ast_variable_declaration() {
 
     // Cria o parser e processa os tokens
     let mut parser = Parser::new(tokens);
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
 
     // Valida o AST gerado
     match ast {
-        ASTNode::VariableDeclaration { identifier, value } => {
+        ASTNode::VariableDeclaration { identifier, value, .. } => {
             // Verifica se o identificador é "nome"
             assert_eq!(identifier, "nome");
 
@@ -120,10 +118,10 @@ fn test_ast_complex_expression_with_numbers() {
     let tokens = vec![
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::NUMBER, "10".to_string()),
-        Token::new(TokenType::OPERATOR, "*".to_string()),
+        Token::new(TokenType::STAR, "*".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::NUMBER, "50".to_string()),
-        Token::new(TokenType::OPERATOR, "/".to_string()),
+        Token::new(TokenType::SLASH, "/".to_string()),
         Token::new(TokenType::NUMBER, "2".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
@@ -132,7 +130,7 @@ fn test_ast_complex_expression_with_numbers() {
 
     // Cria o parser e processa os tokens
     let mut parser = Parser::new(tokens);
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
 
     // Valida o AST gerado
     match ast {
@@ -145,8 +143,7 @@ fn test_ast_complex_expression_with_numbers() {
             }
 
             // Verifica se o operador é "*"
-            assert_eq!(op._type, TokenType::OPERATOR);
-            assert_eq!(op.value, "*");
+            assert_eq!(op, BinaryOperator::Multiply);
 
             // Verifica se a expressão à direita é a divisão
             if let ASTNode::BinaryOp { left, op, right } = *right {
@@ -158,8 +155,7 @@ fn test_ast_complex_expression_with_numbers() {
                 }
 
                 // Verifica se o operador da divisão é "/"
-                assert_eq!(op._type, TokenType::OPERATOR);
-                assert_eq!(op.value, "/");
+                assert_eq!(op, BinaryOperator::Divide);
 
                 // Verifica se o nó à direita da divisão é 2
                 if let ASTNode::Number(value) = *right {
@@ -181,13 +177,13 @@ fn test_ast_complex_expression_with_identifiers() {
     let tokens = vec![
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::IDENTIFIER, "n1".to_string()),
-        Token::new(TokenType::OPERATOR, "*".to_string()),
+        Token::new(TokenType::STAR, "*".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::NUMBER, "50".to_string()),
-        Token::new(TokenType::OPERATOR, "/".to_string()),
+        Token::new(TokenType::SLASH, "/".to_string()),
         Token::new(TokenType::IDENTIFIER, "n2".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
-        Token::new(TokenType::OPERATOR, "+".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
         Token::new(TokenType::NUMBER, "10".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
         Token::new(TokenType::EOF, "".to_string()),
@@ -195,7 +191,7 @@ fn test_ast_complex_expression_with_identifiers() {
 
     // Cria o parser e processa os tokens
     let mut parser = Parser::new(tokens);
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
 
     // Valida o AST gerado
     match ast {
@@ -210,8 +206,7 @@ fn test_ast_complex_expression_with_identifiers() {
                 }
 
                 // Verifica se o operador é "*"
-                assert_eq!(op._type, TokenType::OPERATOR);
-                assert_eq!(op.value, "*");
+                assert_eq!(op, BinaryOperator::Multiply);
 
                 // Verifica a operação à direita da multiplicação (divisão)
                 if let ASTNode::BinaryOp { left, op, right } = *right {
@@ -223,8 +218,7 @@ fn test_ast_complex_expression_with_identifiers() {
                     }
 
                     // Verifica se o operador da divisão é "/"
-                    assert_eq!(op._type, TokenType::OPERATOR);
-                    assert_eq!(op.value, "/");
+                    assert_eq!(op, BinaryOperator::Divide);
 
                     // Verifica se o nó à direita da divisão é n2
                     if let ASTNode::Identifier(value) = *right {
@@ -240,8 +234,7 @@ fn test_ast_complex_expression_with_identifiers() {
             }
 
             // Verifica se o operador da soma é "+"
-            assert_eq!(op._type, TokenType::OPERATOR);
-            assert_eq!(op.value, "+");
+            assert_eq!(op, BinaryOperator::Add);
 
             // Verifica se o nó à direita da soma é 10
             if let ASTNode::Number(value) = *right {
@@ -260,13 +253,13 @@ fn test_ast_mixed_expression() {
     let tokens = vec![
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::IDENTIFIER, "n1".to_string()),
-        Token::new(TokenType::OPERATOR, "*".to_string()),
+        Token::new(TokenType::STAR, "*".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::NUMBER, "50".to_string()),
-        Token::new(TokenType::OPERATOR, "/".to_string()),
+        Token::new(TokenType::SLASH, "/".to_string()),
         Token::new(TokenType::IDENTIFIER, "n2".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
-        Token::new(TokenType::OPERATOR, "+".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
         Token::new(TokenType::NUMBER, "10".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
         Token::new(TokenType::EOF, "".to_string()),
@@ -274,14 +267,13 @@ fn test_ast_mixed_expression() {
 
     // Cria o parser e processa os tokens
     let mut parser = Parser::new(tokens);
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
 
     // Valida o AST gerado
     match ast {
         ASTNode::BinaryOp { left, op, right } => {
             // Verifica se o operador é "+"
-            assert_eq!(op._type, TokenType::OPERATOR);
-            assert_eq!(op.value, "+");
+            assert_eq!(op, BinaryOperator::Add);
 
             // Verifica se a expressão à esquerda é a operação de multiplicação
             if let ASTNode::BinaryOp { left, op, right } = *left {
@@ -293,8 +285,7 @@ fn test_ast_mixed_expression() {
                 }
 
                 // Verifica se o operador da multiplicação é "*"
-                assert_eq!(op._type, TokenType::OPERATOR);
-                assert_eq!(op.value, "*");
+                assert_eq!(op, BinaryOperator::Multiply);
 
                 // Verifica se a operação à direita da multiplicação é a operação de divisão
                 if let ASTNode::BinaryOp { left, op, right } = *right {
@@ -306,8 +297,7 @@ fn test_ast_mixed_expression() {
                     }
 
                     // Verifica se o operador da divisão é "/"
-                    assert_eq!(op._type, TokenType::OPERATOR);
-                    assert_eq!(op.value, "/");
+                    assert_eq!(op, BinaryOperator::Divide);
 
                     // Verifica se o nó à direita da divisão é n2
                     if let ASTNode::Identifier(value) = *right {
@@ -339,40 +329,40 @@ fn test_ast_variable_declaration_with_complex_expression() {
         // var a = 10;
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "a".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "10".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
 
         // var b = 20;
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "b".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "20".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
 
         // var c = 30;
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "c".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "30".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
 
         // var d = 2 * ((a + b) * (c / 2));
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "d".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "2".to_string()),
-        Token::new(TokenType::OPERATOR, "*".to_string()),
+        Token::new(TokenType::STAR, "*".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::IDENTIFIER, "a".to_string()),
-        Token::new(TokenType::OPERATOR, "+".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
         Token::new(TokenType::IDENTIFIER, "b".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
-        Token::new(TokenType::OPERATOR, "*".to_string()),
+        Token::new(TokenType::STAR, "*".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::IDENTIFIER, "c".to_string()),
-        Token::new(TokenType::OPERATOR, "/".to_string()),
+        Token::new(TokenType::SLASH, "/".to_string()),
         Token::new(TokenType::NUMBER, "2".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
@@ -384,10 +374,10 @@ fn test_ast_variable_declaration_with_complex_expression() {
     let mut parser = Parser::new(tokens);
     
     // Testa a variável 'a'
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
     println!("AST Gerado: {:?}", ast);
     match ast {
-        ASTNode::VariableDeclaration { identifier, value } => {
+        ASTNode::VariableDeclaration { identifier, value, .. } => {
             assert_eq!(identifier, "a");
             assert_eq!(*value, ASTNode::Number(10));
         }
@@ -395,10 +385,10 @@ fn test_ast_variable_declaration_with_complex_expression() {
     }
 
     // Testa a variável 'b'
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
     println!("AST Gerado: {:?}", ast);
     match ast {
-        ASTNode::VariableDeclaration { identifier, value } => {
+        ASTNode::VariableDeclaration { identifier, value, .. } => {
             assert_eq!(identifier, "b");
             assert_eq!(*value, ASTNode::Number(20));
         }
@@ -406,10 +396,10 @@ fn test_ast_variable_declaration_with_complex_expression() {
     }
 
     // Testa a variável 'c'
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
     println!("AST Gerado: {:?}", ast);
     match ast {
-        ASTNode::VariableDeclaration { identifier, value } => {
+        ASTNode::VariableDeclaration { identifier, value, .. } => {
             assert_eq!(identifier, "c");
             assert_eq!(*value, ASTNode::Number(30));
         }
@@ -417,54 +407,65 @@ fn test_ast_variable_declaration_with_complex_expression() {
     }
 
     // Testa a variável 'd'
-    let ast = parser.parse();
+    let ast = parser.parse().unwrap();
     println!("AST Gerado: {:?}", ast);
     match ast {
-        ASTNode::VariableDeclaration { identifier, value } => {
+        ASTNode::VariableDeclaration { identifier, value, .. } => {
             assert_eq!(identifier, "d");
             
             if let ASTNode::BinaryOp { left, op, right } = *value {
                 // Verifica o operador '*'
-                assert_eq!(op.value, "*");
+                assert_eq!(op, BinaryOperator::Multiply);
 
-                // Verifica a estrutura do "left" (deveria ser uma operação binária com a soma de a e b)
-                if let ASTNode::BinaryOp { left, op, right } = *left {
-                    // Verifica o operador '+'
-                    assert_eq!(op.value, "+");
+                // Verifica o "left" (deveria ser o número 2)
+                assert_eq!(*left, ASTNode::Number(2));
 
-                    if let ASTNode::Identifier(identifier) = *left {
-                        assert_eq!(identifier, "a");
-                    } else {
-                        panic!("Esperado identificador 'a'");
-                    }
+                // Verifica a estrutura do "right" (deveria ser a multiplicação de (a + b) por (c / 2))
+                if let ASTNode::BinaryOp { left, op, right } = *right {
+                    // Verifica o operador '*'
+                    assert_eq!(op, BinaryOperator::Multiply);
 
-                    if let ASTNode::Identifier(identifier) = *right {
-                        assert_eq!(identifier, "b");
-                    } else {
-                        panic!("Esperado identificador 'b'");
-                    }
-                } else {
-                    panic!("Esperado operador de soma");
-                }
+                    // Verifica a estrutura do "left" (deveria ser uma operação binária com a soma de a e b)
+                    if let ASTNode::BinaryOp { left, op, right } = *left {
+                        // Verifica o operador '+'
+                        assert_eq!(op, BinaryOperator::Add);
 
-                // Verifica a estrutura do "right" (deveria ser uma operação binária com a divisão de c por 2)
-                if let ASTNode::BinaryOp { left, op, right } = *right {
-                    // Verifica o operador '/'
-                    assert_eq!(op.value, "/");
+                        if let ASTNode::Identifier(identifier) = *left {
+                            assert_eq!(identifier, "a");
+                        } else {
+                            panic!("Esperado identificador 'a'");
+                        }
 
-                    if let ASTNode::Identifier(identifier) = *left {
-                        assert_eq!(identifier, "c");
+                        if let ASTNode::Identifier(identifier) = *right {
+                            assert_eq!(identifier, "b");
+                        } else {
+                            panic!("Esperado identificador 'b'");
+                        }
                     } else {
-                        panic!("Esperado identificador 'c'");
+                        panic!("Esperado operador de soma");
                     }
 
-                    if let ASTNode::Number(value) = *right {
-                        assert_eq!(value, 2);
+                    // Verifica a estrutura do "right" (deveria ser uma operação binária com a divisão de c por 2)
+                    if let ASTNode::BinaryOp { left, op, right } = *right {
+                        // Verifica o operador '/'
+                        assert_eq!(op, BinaryOperator::Divide);
+
+                        if let ASTNode::Identifier(identifier) = *left {
+                            assert_eq!(identifier, "c");
+                        } else {
+                            panic!("Esperado identificador 'c'");
+                        }
+
+                        if let ASTNode::Number(value) = *right {
+                            assert_eq!(value, 2);
+                        } else {
+                            panic!("Esperado número 2");
+                        }
                     } else {
-                        panic!("Esperado número 2");
+                        panic!("Esperado operador de divisão");
                     }
                 } else {
-                    panic!("Esperado operador de divisão");
+                    panic!("Esperado operador de multiplicação");
                 }
             } else {
                 panic!("Esperado uma operação binária para 'd'");
@@ -472,4 +473,1031 @@ fn test_ast_variable_declaration_with_complex_expression() {
         }
         _ => panic!("AST não representa a declaração de variável correta"),
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_ast_error_missing_semicolon() {
+    // Cria os tokens correspondentes à declaração incompleta: var nome = 10
+    let tokens = vec![
+        Token::new(TokenType::KEYWORD, "var".to_string()),
+        Token::new(TokenType::IDENTIFIER, "nome".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
+        Token::new(TokenType::NUMBER, "10".to_string()),
+        Token::new(TokenType::EOF, "".to_string()),
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let result = parser.parse();
+
+    match result {
+        Err(ParseError { kind: ParseErrorKind::UnexpectedToken { expected }, found }) => {
+            assert_eq!(expected, vec![TokenType::SEMICOLON]);
+            assert_eq!(found._type, TokenType::EOF);
+        }
+        _ => panic!("Parser não detectou a falta do ';': {:?}", result),
+    }
+}
+
+#[test]
+fn test_ast_error_reports_location() {
+    // var = 10; (falta o identificador)
+    let mut lexer = Lexer::new("var = 10;");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.expected(), &[TokenType::IDENTIFIER]);
+    assert_eq!(error.found._type, TokenType::EQUAL);
+    assert_eq!((error.span().start_line, error.span().start_column), (1, 5));
+    assert_eq!(error.to_string(), "Erro: esperava IDENTIFIER, encontrou '=' na linha 1 e coluna 5");
+}
+
+#[test]
+fn test_ast_error_unexpected_end_of_tokens() {
+    // ( 1 + 2 (sem o fechamento do parêntese e sem token EOF)
+    let tokens = vec![
+        Token::new(TokenType::LPAREN, "(".to_string()),
+        Token::new(TokenType::NUMBER, "1".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
+        Token::new(TokenType::NUMBER, "2".to_string()),
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.expected(), &[TokenType::RPAREN]);
+    assert_eq!(error.found._type, TokenType::EOF);
+}
+
+#[test]
+fn test_ast_error_unexpected_end_of_file_points_at_last_line() {
+    // A expressão termina no meio, seguida de linhas em branco e um comentário
+    let mut lexer = Lexer::new("var a = 1;\nvar b = (a +\n\n// fim\n");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    parser.parse_program();
+
+    assert_eq!(parser.errors().len(), 1);
+    let error = &parser.errors()[0];
+    assert_eq!(error.found._type, TokenType::EOF);
+    assert_eq!((error.span().start_line, error.span().start_column), (2, 13));
+    assert_eq!(
+        error.to_string(),
+        "Erro: fim do arquivo inesperado, esperava NUMBER ou STRING ou FSTRING_START ou IDENTIFIER ou LPAREN na linha 2 e coluna 13"
+    );
+}
+
+#[test]
+fn test_ast_error_recovery_reports_all_errors() {
+    // Três instruções com erro intercaladas com instruções válidas
+    let code = "var a = ;\nvar b = 2;\nvar = 3;\nb * 4;\nvar c = (1 + 2;\n}\nvar d = 5;";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let statements = match parser.parse_program() {
+        ASTNode::Program(statements) => statements,
+        ast => panic!("AST não representa um programa: {:?}", ast),
+    };
+
+    // As instruções válidas continuam no AST parcial
+    assert_eq!(statements.len(), 3, "AST parcial inesperado: {:?}", statements);
+    assert!(matches!(&statements[0], ASTNode::VariableDeclaration { identifier, .. } if identifier == "b"));
+    assert!(matches!(&statements[1], ASTNode::BinaryOp { .. }));
+    assert!(matches!(&statements[2], ASTNode::VariableDeclaration { identifier, .. } if identifier == "d"));
+
+    // Todos os erros são registrados, cada um na sua linha
+    let lines: Vec<usize> = parser.errors().iter().map(|error| error.span().start_line).collect();
+    assert_eq!(lines, vec![1, 3, 5, 6], "Erros inesperados: {:?}", parser.errors());
+}
+
+
+#[test]
+fn test_ast_program_with_multiple_statements() {
+    let code = "var a = 1;\nvar b = a * 2;\n{\n    var c = a + b;\n    c;\n}\nb / 2;";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    match ast {
+        ASTNode::Program(statements) => {
+            assert_eq!(statements.len(), 4, "Número inesperado de instruções: {:?}", statements);
+
+            match &statements[0] {
+                ASTNode::VariableDeclaration { identifier, value, .. } => {
+                    assert_eq!(identifier, "a");
+                    assert_eq!(**value, ASTNode::Number(1));
+                }
+                _ => panic!("Primeira instrução não é a declaração de 'a'"),
+            }
+
+            assert!(matches!(&statements[1], ASTNode::VariableDeclaration { identifier, .. } if identifier == "b"));
+
+            // O bloco contém suas próprias instruções
+            match &statements[2] {
+                ASTNode::Block(block) => {
+                    assert_eq!(block.len(), 2);
+                    assert!(matches!(&block[0], ASTNode::VariableDeclaration { identifier, .. } if identifier == "c"));
+                    assert_eq!(block[1], ASTNode::Identifier("c".to_string()));
+                }
+                _ => panic!("Terceira instrução não é um bloco"),
+            }
+
+            assert!(matches!(&statements[3], ASTNode::BinaryOp { .. }));
+        }
+        _ => panic!("AST não representa um programa"),
+    }
+}
+
+#[test]
+fn test_ast_program_stops_at_eof() {
+    // var a = 1; EOF (tokens após o EOF são ignorados)
+    let tokens = vec![
+        Token::new(TokenType::KEYWORD, "var".to_string()),
+        Token::new(TokenType::IDENTIFIER, "a".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
+        Token::new(TokenType::NUMBER, "1".to_string()),
+        Token::new(TokenType::SEMICOLON, ";".to_string()),
+        Token::new(TokenType::EOF, "".to_string()),
+        Token::new(TokenType::NUMBER, "2".to_string()),
+    ];
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+    assert!(matches!(ast, ASTNode::Program(statements) if statements.len() == 1));
+}
+
+#[test]
+fn test_ast_program_unclosed_block() {
+    let mut lexer = Lexer::new("{ var a = 1;");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    parser.parse_program();
+
+    assert_eq!(parser.errors().len(), 1);
+    assert_eq!(parser.errors()[0].expected(), &[TokenType::RBRACKET]);
+    assert_eq!(parser.errors()[0].found._type, TokenType::EOF);
+}
+
+#[test]
+fn test_ast_function_declaration() {
+    let code = "função soma(n1, n2) {\n    var resultado = n1 + n2;\n    retorne resultado;\n}\nfunção nada() { retorne; }";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    let statements = match ast {
+        ASTNode::Program(statements) => statements,
+        _ => panic!("AST não representa um programa"),
+    };
+    assert_eq!(statements.len(), 2);
+
+    match &statements[0] {
+        ASTNode::FunctionDeclaration { name, params, body, .. } => {
+            assert_eq!(name, "soma");
+            assert_eq!(params, &vec!["n1".to_string(), "n2".to_string()]);
+
+            match body.as_ref() {
+                ASTNode::Block(block) => {
+                    assert_eq!(block.len(), 2);
+                    assert!(matches!(&block[0], ASTNode::VariableDeclaration { identifier, .. } if identifier == "resultado"));
+                    assert_eq!(block[1], ASTNode::Return(Some(Box::new(ASTNode::Identifier("resultado".to_string())))));
+                }
+                _ => panic!("Corpo da função não é um bloco"),
+            }
+        }
+        _ => panic!("AST não representa uma declaração de função"),
+    }
+
+    // Função sem parâmetros com um `retorne` sem valor
+    assert_eq!(statements[1], ASTNode::FunctionDeclaration {
+        name: "nada".to_string(),
+        params: Vec::new(),
+        body: Box::new(ASTNode::Block(vec![ASTNode::Return(None)])),
+        doc: None,
+    });
+}
+
+#[test]
+fn test_ast_function_declaration_invalid_params() {
+    let mut lexer = Lexer::new("função soma(n1 n2) { retorne n1; }");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    parser.parse_program();
+
+    assert!(!parser.errors().is_empty());
+    assert_eq!(parser.errors()[0].expected(), &[TokenType::COMMA, TokenType::RPAREN]);
+    assert_eq!(parser.errors()[0].found.value, "n2");
+}
+
+#[test]
+fn test_ast_for_loop() {
+    let code = "para (var índice = 0; índice < 10; índice += 1) {\n    var x = índice * 2;\n}";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::For { init, condition, update, body } => {
+            // var índice = 0;
+            match init.as_deref() {
+                Some(ASTNode::VariableDeclaration { identifier, value, .. }) => {
+                    assert_eq!(identifier, "índice");
+                    assert_eq!(**value, ASTNode::Number(0));
+                }
+                _ => panic!("Inicialização não é uma declaração de variável"),
+            }
+
+            // índice < 10
+            match condition.as_deref() {
+                Some(ASTNode::BinaryOp { left, op, right }) => {
+                    assert_eq!(**left, ASTNode::Identifier("índice".to_string()));
+                    assert_eq!(*op, BinaryOperator::Less);
+                    assert_eq!(**right, ASTNode::Number(10));
+                }
+                _ => panic!("Condição não é uma comparação"),
+            }
+
+            // índice += 1
+            match update.as_deref() {
+                Some(ASTNode::Assignment { identifier, op, value }) => {
+                    assert_eq!(identifier, "índice");
+                    assert_eq!(*op, AssignOperator::Add);
+                    assert_eq!(**value, ASTNode::Number(1));
+                }
+                _ => panic!("Atualização não é uma atribuição composta"),
+            }
+
+            assert!(matches!(*body, ASTNode::Block(ref block) if block.len() == 1));
+        }
+        _ => panic!("AST não representa um laço para"),
+    }
+}
+
+#[test]
+fn test_ast_for_loop_with_empty_clauses() {
+    let mut lexer = Lexer::new("para (;;) { }");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    assert_eq!(ast, ASTNode::For {
+        init: None,
+        condition: None,
+        update: None,
+        body: Box::new(ASTNode::Block(Vec::new())),
+    });
+}
+
+#[test]
+fn test_ast_function_calls() {
+    let mut lexer = Lexer::new("imprimir(soma(x, índice), dobro(2) * 3, nada())");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    let identifier = |name: &str| Box::new(ASTNode::Identifier(name.to_string()));
+
+    match ast {
+        ASTNode::Call { callee, args } => {
+            assert_eq!(callee, identifier("imprimir"));
+            assert_eq!(args.len(), 3);
+
+            // Chamada como argumento: soma(x, índice)
+            assert_eq!(args[0], ASTNode::Call {
+                callee: identifier("soma"),
+                args: vec![*identifier("x"), *identifier("índice")],
+            });
+
+            // Chamada dentro de uma expressão: dobro(2) * 3
+            match &args[1] {
+                ASTNode::BinaryOp { left, op, right } => {
+                    assert_eq!(**left, ASTNode::Call { callee: identifier("dobro"), args: vec![ASTNode::Number(2)] });
+                    assert_eq!(*op, BinaryOperator::Multiply);
+                    assert_eq!(**right, ASTNode::Number(3));
+                }
+                _ => panic!("Segundo argumento não é uma multiplicação"),
+            }
+
+            // Chamada sem argumentos
+            assert_eq!(args[2], ASTNode::Call { callee: identifier("nada"), args: Vec::new() });
+        }
+        _ => panic!("AST não representa uma chamada de função"),
+    }
+}
+
+#[test]
+fn test_ast_chained_calls() {
+    let mut lexer = Lexer::new("criar(1)(2);");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+    assert_eq!(ast, ASTNode::Program(vec![ASTNode::Call {
+        callee: Box::new(ASTNode::Call {
+            callee: Box::new(ASTNode::Identifier("criar".to_string())),
+            args: vec![ASTNode::Number(1)],
+        }),
+        args: vec![ASTNode::Number(2)],
+    }]));
+}
+
+#[test]
+fn test_ast_call_missing_comma() {
+    let mut lexer = Lexer::new("soma(x índice)");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.expected(), &[TokenType::COMMA, TokenType::RPAREN]);
+    assert_eq!(error.found.value, "índice");
+}
+
+#[test]
+fn test_ast_assignments() {
+    let mut lexer = Lexer::new("x = soma(x, 1);\ntotal *= x + 2;\na = b -= 3;");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    let statements = match ast {
+        ASTNode::Program(statements) => statements,
+        _ => panic!("AST não representa um programa"),
+    };
+    assert_eq!(statements.len(), 3);
+
+    // x = soma(x, 1);
+    match &statements[0] {
+        ASTNode::Assignment { identifier, op, value } => {
+            assert_eq!(identifier, "x");
+            assert_eq!(*op, AssignOperator::Assign);
+            assert!(matches!(value.as_ref(), ASTNode::Call { .. }));
+        }
+        _ => panic!("Primeira instrução não é uma atribuição"),
+    }
+
+    // total *= x + 2;
+    match &statements[1] {
+        ASTNode::Assignment { identifier, op, value } => {
+            assert_eq!(identifier, "total");
+            assert_eq!(*op, AssignOperator::Multiply);
+            assert!(matches!(value.as_ref(), ASTNode::BinaryOp { op, .. } if *op == BinaryOperator::Add));
+        }
+        _ => panic!("Segunda instrução não é uma atribuição composta"),
+    }
+
+    // a = b -= 3; é associativa à direita: a = (b -= 3)
+    match &statements[2] {
+        ASTNode::Assignment { identifier, op, value } => {
+            assert_eq!(identifier, "a");
+            assert_eq!(*op, AssignOperator::Assign);
+
+            match value.as_ref() {
+                ASTNode::Assignment { identifier, op, value } => {
+                    assert_eq!(identifier, "b");
+                    assert_eq!(*op, AssignOperator::Subtract);
+                    assert_eq!(**value, ASTNode::Number(3));
+                }
+                _ => panic!("Atribuição interna não encontrada"),
+            }
+        }
+        _ => panic!("Terceira instrução não é uma atribuição"),
+    }
+}
+
+#[test]
+fn test_ast_invalid_assignment_target() {
+    let mut lexer = Lexer::new("1 + x = 2;");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    parser.parse_program();
+
+    assert_eq!(parser.errors().len(), 1);
+    assert_eq!(parser.errors()[0].kind, ParseErrorKind::InvalidAssignmentTarget);
+    assert_eq!(parser.errors()[0].found.value, "=");
+}
+
+#[test]
+fn test_ast_parse_lexer_sample_program() {
+    let code = r#"
+        var x = 1;
+        função soma(n1, n2) {
+            var resultado = n1 + n2;
+            retorne resultado;
+        }
+        para (var índice = 0; índice < 10; índice += 1) {
+            x = soma(x, índice);
+            imprimir(x);
+        }
+
+        imprimir(x);
+    "#;
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    match ast {
+        ASTNode::Program(statements) => {
+            assert_eq!(statements.len(), 4);
+            assert!(matches!(&statements[0], ASTNode::VariableDeclaration { .. }));
+            assert!(matches!(&statements[1], ASTNode::FunctionDeclaration { .. }));
+            assert!(matches!(&statements[3], ASTNode::Call { .. }));
+
+            match &statements[2] {
+                ASTNode::For { body, .. } => match body.as_ref() {
+                    ASTNode::Block(block) => {
+                        assert_eq!(block.len(), 2);
+                        assert!(matches!(&block[0], ASTNode::Assignment { identifier, .. } if identifier == "x"));
+                        assert!(matches!(&block[1], ASTNode::Call { .. }));
+                    }
+                    _ => panic!("Corpo do laço não é um bloco"),
+                },
+                _ => panic!("Terceira instrução não é um laço para"),
+            }
+        }
+        _ => panic!("AST não representa um programa"),
+    }
+}
+
+#[test]
+fn test_ast_comparison_and_logical_precedence() {
+    // a < 1 || a > 10 && b == c + 1 deve ser a < 1 || (a > 10 && (b == (c + 1)))
+    let mut lexer = Lexer::new("a < 1 || a > 10 && b == c + 1");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::LogicalOp { left, op, right } => {
+            assert_eq!(op, LogicalOperator::Or);
+            assert!(matches!(*left, ASTNode::BinaryOp { ref op, .. } if *op == BinaryOperator::Less));
+
+            match *right {
+                ASTNode::LogicalOp { left, op, right } => {
+                    assert_eq!(op, LogicalOperator::And);
+                    assert!(matches!(*left, ASTNode::BinaryOp { ref op, .. } if *op == BinaryOperator::Greater));
+
+                    match *right {
+                        ASTNode::BinaryOp { left, op, right } => {
+                            assert_eq!(op, BinaryOperator::Equal);
+                            assert_eq!(*left, ASTNode::Identifier("b".to_string()));
+                            assert!(matches!(*right, ASTNode::BinaryOp { ref op, .. } if *op == BinaryOperator::Add));
+                        }
+                        _ => panic!("Esperava uma comparação de igualdade"),
+                    }
+                }
+                _ => panic!("Esperava uma operação '&&'"),
+            }
+        }
+        _ => panic!("AST não representa uma operação '||'"),
+    }
+}
+
+#[test]
+fn test_ast_logical_operators_are_left_associative() {
+    let mut lexer = Lexer::new("a || b || c");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::LogicalOp { left, right, .. } => {
+            assert!(matches!(*left, ASTNode::LogicalOp { .. }));
+            assert_eq!(*right, ASTNode::Identifier("c".to_string()));
+        }
+        _ => panic!("AST não representa uma operação '||'"),
+    }
+}
+
+#[test]
+fn test_ast_unary_operators() {
+    // -5 * -(a + b) deve ser (-5) * (-(a + b)), com o -5 já como literal negativo
+    let mut lexer = Lexer::new("-5 * -(a + b)");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::BinaryOp { left, op, right } => {
+            assert_eq!(op, BinaryOperator::Multiply);
+
+            assert_eq!(*left, ASTNode::Number(-5));
+
+            match *right {
+                ASTNode::UnaryOp { op, operand } => {
+                    assert_eq!(op, UnaryOperator::Negate);
+                    assert!(matches!(*operand, ASTNode::BinaryOp { ref op, .. } if *op == BinaryOperator::Add));
+                }
+                _ => panic!("Nó à direita não é uma negação"),
+            }
+        }
+        _ => panic!("AST não representa uma multiplicação"),
+    }
+}
+
+#[test]
+fn test_ast_logical_not_and_nested_unary() {
+    // !pronto && !!ativo deve ser (!pronto) && (!(!ativo))
+    let mut lexer = Lexer::new("!pronto && !!ativo");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::LogicalOp { left, op, right } => {
+            assert_eq!(op, LogicalOperator::And);
+            assert!(matches!(*left, ASTNode::UnaryOp { ref op, ref operand } if *op == UnaryOperator::Not && **operand == ASTNode::Identifier("pronto".to_string())));
+
+            match *right {
+                ASTNode::UnaryOp { op, operand } => {
+                    assert_eq!(op, UnaryOperator::Not);
+                    assert!(matches!(*operand, ASTNode::UnaryOp { ref op, .. } if *op == UnaryOperator::Not));
+                }
+                _ => panic!("Nó à direita não é uma negação lógica"),
+            }
+        }
+        _ => panic!("AST não representa uma operação '&&'"),
+    }
+}
+
+// Reescreve uma expressão com parênteses explícitos, para comparar a
+// precedência de expressões longas
+fn parenthesize(node: &ASTNode) -> String {
+    match node {
+        ASTNode::Number(value) => value.to_string(),
+        ASTNode::Identifier(name) => name.clone(),
+        ASTNode::BinaryOp { left, op, right } => format!("({} {} {})", parenthesize(left), op, parenthesize(right)),
+        ASTNode::LogicalOp { left, op, right } => format!("({} {} {})", parenthesize(left), op, parenthesize(right)),
+        ASTNode::UnaryOp { op, operand } => format!("({}{})", op, parenthesize(operand)),
+        ASTNode::Assignment { identifier, op, value } => format!("({} {} {})", identifier, op, parenthesize(value)),
+        _ => panic!("Nó inesperado em uma expressão: {:?}", node),
+    }
+}
+
+#[test]
+fn test_ast_operator_precedence_table() {
+    let cases = [
+        ("a = b || c && d == e < f + g * -h", "(a = (b || (c && (d == (e < (f + (g * (-h))))))))"),
+        ("a * b + c * d - e / f", "(((a * b) + (c * d)) - (e / f))"),
+        ("a < b == c > d", "((a < b) == (c > d))"),
+        ("!a && b || !c", "(((!a) && b) || (!c))"),
+        ("a = b += c - d - e", "(a = (b += ((c - d) - e)))"),
+        ("--a * +b", "((-(-a)) * (+b))"),
+        ("a | b ^ c & d << e + f % g", "(a | (b ^ (c & (d << (e + (f % g))))))"),
+        ("a == b | c", "(a == (b | c))"),
+        ("2 ** 3 ** 2", "(2 ** (3 ** 2))"),
+        ("-2 ** -x * 3", "((-(2 ** (-x))) * 3)"),
+        ("~a >> 1 <= b", "(((~a) >> 1) <= b)"),
+        ("a <<= b **= 2", "(a <<= (b **= 2))"),
+        ("a + b div c * d", "(a + ((b div c) * d))"),
+        ("a div= b div 2", "(a div= (b div 2))"),
+    ];
+
+    for (code, expected) in cases {
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(lexer.tokenize().unwrap());
+
+        assert_eq!(parenthesize(&parser.parse().unwrap()), expected, "Precedência incorreta em {}", code);
+    }
+}
+
+#[test]
+fn test_ast_binding_powers() {
+    let operator = |_type, value: &str| infix_operator(&Token::new(_type, value.to_string())).unwrap();
+
+    let plus = operator(TokenType::PLUS, "+");
+    let times = operator(TokenType::STAR, "*");
+    let assign = operator(TokenType::EQUAL, "=");
+
+    assert_eq!(plus.precedence, Precedence::Additive);
+    assert!(times.precedence > plus.precedence);
+    assert_eq!(assign.associativity, Associativity::Right);
+
+    // Associativo à esquerda: o lado direito se liga com mais força
+    let (left_bp, right_bp) = plus.binding_power();
+    assert!(left_bp < right_bp);
+    let (left_bp, right_bp) = assign.binding_power();
+    assert!(left_bp > right_bp);
+
+    assert_eq!(plus.kind, InfixKind::Binary(BinaryOperator::Add));
+    assert_eq!(assign.kind, InfixKind::Assignment(AssignOperator::Assign));
+
+    let negate = prefix_operator(&Token::new(TokenType::MINUS, "-".to_string()));
+    assert_eq!(negate, Some(UnaryOperator::Negate));
+    assert!(Precedence::Unary.binding_power() > times.binding_power().0);
+
+    assert!(infix_operator(&Token::new(TokenType::IDENTIFIER, "+".to_string())).is_none());
+    assert!(prefix_operator(&Token::new(TokenType::STAR, "*".to_string())).is_none());
+}
+
+#[test]
+fn test_ast_if_else_chain() {
+    let code = "se (x < 1 || x > 10) {\n    imprimir(1);\n} senão se (x == 5) {\n    imprimir(5);\n} senão {\n    imprimir(x);\n}";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::If { condition, then_branch, else_branch } => {
+            assert!(matches!(*condition, ASTNode::LogicalOp { ref op, .. } if *op == LogicalOperator::Or));
+            assert!(matches!(*then_branch, ASTNode::Block(ref block) if block.len() == 1));
+
+            // senão se (x == 5) { ... } senão { ... }
+            match else_branch.as_deref() {
+                Some(ASTNode::If { condition, then_branch, else_branch }) => {
+                    assert!(matches!(condition.as_ref(), ASTNode::BinaryOp { op, .. } if *op == BinaryOperator::Equal));
+                    assert!(matches!(then_branch.as_ref(), ASTNode::Block(block) if block.len() == 1));
+                    assert!(matches!(else_branch.as_deref(), Some(ASTNode::Block(block)) if block.len() == 1));
+                }
+                _ => panic!("Esperava um 'senão se'"),
+            }
+        }
+        _ => panic!("AST não representa uma condicional"),
+    }
+}
+
+#[test]
+fn test_ast_if_without_else() {
+    let mut lexer = Lexer::new("se (pronto) { }");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    assert_eq!(ast, ASTNode::If {
+        condition: Box::new(ASTNode::Identifier("pronto".to_string())),
+        then_branch: Box::new(ASTNode::Block(Vec::new())),
+        else_branch: None,
+    });
+}
+
+#[test]
+fn test_ast_if_missing_parenthesis() {
+    let mut lexer = Lexer::new("se pronto { }");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.expected(), &[TokenType::LPAREN]);
+    assert_eq!(error.found.value, "pronto");
+}
+
+#[test]
+fn test_ast_while_with_loop_control() {
+    let code = "enquanto (x < 10) {\n    se (x == 5) { pare; }\n    x += 1;\n    continue;\n}";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::While { condition, body } => {
+            assert!(matches!(*condition, ASTNode::BinaryOp { ref op, .. } if *op == BinaryOperator::Less));
+
+            match *body {
+                ASTNode::Block(block) => {
+                    assert_eq!(block.len(), 3);
+
+                    // `pare` dentro de um `se` continua dentro do laço
+                    match &block[0] {
+                        ASTNode::If { then_branch, .. } => {
+                            assert_eq!(**then_branch, ASTNode::Block(vec![ASTNode::Break]));
+                        }
+                        _ => panic!("Primeira instrução não é uma condicional"),
+                    }
+
+                    assert!(matches!(&block[1], ASTNode::Assignment { .. }));
+                    assert_eq!(block[2], ASTNode::Continue);
+                }
+                _ => panic!("Corpo do laço não é um bloco"),
+            }
+        }
+        _ => panic!("AST não representa um laço enquanto"),
+    }
+}
+
+#[test]
+fn test_ast_loop_control_outside_loop() {
+    // `pare` fora de laço, `continue` dentro de uma função declarada em um laço
+    // e um `pare` válido depois dos erros
+    let code = "pare;\npara (;;) {\n    função f() { continue; }\n    pare;\n}";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    parser.parse_program();
+
+    let errors = parser.errors();
+    assert_eq!(errors.len(), 2, "Erros inesperados: {:?}", errors);
+
+    assert_eq!(errors[0].kind, ParseErrorKind::OutsideLoop);
+    assert_eq!((errors[0].found.value.as_str(), errors[0].span().start_line), ("pare", 1));
+
+    assert_eq!(errors[1].kind, ParseErrorKind::OutsideLoop);
+    assert_eq!((errors[1].found.value.as_str(), errors[1].span().start_line), ("continue", 3));
+}
+
+#[test]
+fn test_ast_float_literals() {
+    let mut lexer = Lexer::new("var taxa = 2.75 * 2 + 1e-9;");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse().unwrap();
+
+    match ast {
+        ASTNode::VariableDeclaration { identifier, value, .. } => {
+            assert_eq!(identifier, "taxa");
+
+            match *value {
+                ASTNode::BinaryOp { left, right, .. } => {
+                    assert!(matches!(*left, ASTNode::BinaryOp { ref left, .. } if **left == ASTNode::Float(2.75)));
+                    assert_eq!(*right, ASTNode::Float(1e-9));
+                }
+                _ => panic!("Valor não é uma soma"),
+            }
+        }
+        _ => panic!("AST não representa uma declaração de variável"),
+    }
+}
+
+#[test]
+fn test_ast_integer_overflow_is_an_error() {
+    let mut lexer = Lexer::new("99999999999999999999");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.kind, ParseErrorKind::IntegerOverflow);
+    assert_eq!(error.found.value, "99999999999999999999");
+}
+
+#[test]
+fn test_ast_negative_number_literals() {
+    let cases = [
+        ("-9223372036854775808", ASTNode::Number(i64::MIN)),
+        ("-0x7FFF_FFFF_FFFF_FFFF", ASTNode::Number(-i64::MAX)),
+        ("- 2.5", ASTNode::Float(-2.5)),
+    ];
+
+    for (code, expected) in cases {
+        let mut lexer = Lexer::new(code);
+        let mut parser = Parser::new(lexer.tokenize().unwrap());
+
+        assert_eq!(parser.parse().unwrap(), expected, "Literal incorreto em {}", code);
+    }
+
+    let mut lexer = Lexer::new("-9223372036854775809");
+    let mut parser = Parser::new(lexer.tokenize().unwrap());
+    assert_eq!(parser.parse().unwrap_err().kind, ParseErrorKind::IntegerOverflow);
+
+    // O `**` se liga mais forte que o `-`, então o literal não é negado antes
+    let mut lexer = Lexer::new("-2 ** 2");
+    let mut parser = Parser::new(lexer.tokenize().unwrap());
+    assert!(matches!(parser.parse().unwrap(), ASTNode::UnaryOp { op: UnaryOperator::Negate, .. }));
+}
+
+#[test]
+fn test_ast_float_overflow_is_an_error() {
+    let mut lexer = Lexer::new("1e999 + 1.5E+400");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.kind, ParseErrorKind::FloatOverflow);
+    assert_eq!(error.found.value, "1e999");
+}
+
+#[test]
+fn test_ast_radix_integer_literals() {
+    let mut lexer = Lexer::new("0x1F + 0b1010 + 0o755 + 0xFF_FF + 0x7FFF_FFFF_FFFF_FFFF");
+    let tokens = lexer.tokenize().unwrap();
+
+    let numbers: Vec<ASTNode> = tokens.into_iter()
+        .filter(|token| token._type == TokenType::NUMBER)
+        .map(|token| Parser::new(vec![token]).parse().unwrap())
+        .collect();
+
+    assert_eq!(numbers, vec![
+        ASTNode::Number(31),
+        ASTNode::Number(10),
+        ASTNode::Number(493),
+        ASTNode::Number(65535),
+        ASTNode::Number(i64::MAX),
+    ]);
+}
+
+#[test]
+fn test_ast_radix_integer_overflow() {
+    let mut lexer = Lexer::new("0x8000_0000_0000_0000");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.kind, ParseErrorKind::IntegerOverflow);
+    assert_eq!(error.to_string(), "Erro: o número '0x8000000000000000' é grande demais para um inteiro na linha 1 e coluna 1");
+}
+
+#[test]
+fn test_ast_string_literals() {
+    let mut lexer = Lexer::new("var y = 'hello world';\nimprimir(\"olá\\n\", y);");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+    assert_eq!(ast, ASTNode::Program(vec![
+        ASTNode::VariableDeclaration {
+            identifier: "y".to_string(),
+            value: Box::new(ASTNode::String("hello world".to_string())),
+            doc: None,
+        },
+        ASTNode::Call {
+            callee: Box::new(ASTNode::Identifier("imprimir".to_string())),
+            args: vec![
+                ASTNode::String("olá\n".to_string()),
+                ASTNode::Identifier("y".to_string()),
+            ],
+        },
+    ]));
+}
+
+#[test]
+fn test_ast_interpolated_string() {
+    let code = r#"imprimir(f"O número que escolhi foi {randomNumber}, {{não}} {f'{a + 1}'}!");"#;
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    let call_args = match ast {
+        ASTNode::Program(mut statements) => match statements.remove(0) {
+            ASTNode::Call { args, .. } => args,
+            _ => panic!("Instrução não é uma chamada"),
+        },
+        _ => panic!("AST não representa um programa"),
+    };
+
+    match &call_args[0] {
+        ASTNode::InterpolatedString(parts) => {
+            assert_eq!(parts.len(), 5);
+            assert_eq!(parts[0], ASTNode::String("O número que escolhi foi ".to_string()));
+            assert_eq!(parts[1], ASTNode::Identifier("randomNumber".to_string()));
+            assert_eq!(parts[2], ASTNode::String(", {não} ".to_string()));
+
+            // f-string aninhada com uma expressão completa
+            match &parts[3] {
+                ASTNode::InterpolatedString(inner) => {
+                    assert_eq!(inner.len(), 1);
+                    assert!(matches!(&inner[0], ASTNode::BinaryOp { op, .. } if *op == BinaryOperator::Add));
+                }
+                _ => panic!("Esperava uma f-string aninhada"),
+            }
+
+            assert_eq!(parts[4], ASTNode::String("!".to_string()));
+        }
+        _ => panic!("Argumento não é uma f-string"),
+    }
+}
+
+#[test]
+fn test_ast_interpolated_string_empty_expression() {
+    let mut lexer = Lexer::new("f\"{}\"");
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let error = parser.parse().unwrap_err();
+
+    assert_eq!(error.found._type, TokenType::INTERPOLATION_END);
+}
+
+#[test]
+fn test_ast_doc_comments_attached_to_declarations() {
+    let code = r#"
+        /// Valor inicial.
+        var x = 1;
+
+        /// Soma dois números.
+        ///
+        /// Retorna `n1 + n2`.
+        função soma(n1, n2) {
+            /// Documentação solta no fim do bloco
+            retorne n1 + n2;
+            /// Também ignorada
+        }
+
+        /// Ignorada: não precede uma declaração
+        imprimir(x);
+        var y = 2;
+        /// Solta no fim do arquivo
+    "#;
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    let statements = match ast {
+        ASTNode::Program(statements) => statements,
+        _ => panic!("AST não representa um programa"),
+    };
+    assert_eq!(statements.len(), 4);
+
+    match &statements[0] {
+        ASTNode::VariableDeclaration { doc, .. } => assert_eq!(doc.as_deref(), Some("Valor inicial.")),
+        _ => panic!("Primeira instrução não é uma declaração de variável"),
+    }
+
+    match &statements[1] {
+        ASTNode::FunctionDeclaration { doc, body, .. } => {
+            assert_eq!(doc.as_deref(), Some("Soma dois números.\n\nRetorna `n1 + n2`."));
+            assert!(matches!(body.as_ref(), ASTNode::Block(block) if block.len() == 1));
+        }
+        _ => panic!("Segunda instrução não é uma declaração de função"),
+    }
+
+    assert!(matches!(&statements[2], ASTNode::Call { .. }));
+
+    match &statements[3] {
+        ASTNode::VariableDeclaration { doc, .. } => assert_eq!(*doc, None),
+        _ => panic!("Quarta instrução não é uma declaração de variável"),
+    }
+}
+
+#[test]
+fn test_ast_doc_comments_inside_statements_are_ignored() {
+    let code = r#"
+        x = /// no meio da expressão
+            1;
+        soma(
+            /// primeiro argumento
+            a,
+            b /// segundo argumento
+        );
+        se (a) { } /// antes do senão
+        senão { }
+    "#;
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(tokens);
+    let ast = parser.parse_program();
+
+    assert!(parser.errors().is_empty(), "Erros inesperados: {:?}", parser.errors());
+
+    let statements = match ast {
+        ASTNode::Program(statements) => statements,
+        _ => panic!("AST não representa um programa"),
+    };
+    assert_eq!(statements.len(), 3);
+
+    assert!(matches!(&statements[0], ASTNode::Assignment { value, .. } if **value == ASTNode::Number(1)));
+    assert!(matches!(&statements[1], ASTNode::Call { args, .. } if args.len() == 2));
+    assert!(matches!(&statements[2], ASTNode::If { else_branch: Some(_), .. }));
+}
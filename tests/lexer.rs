@@ -1,4 +1,4 @@
-use interpreter::{lexer::Lexer, token::{Token, TokenType}};
+use interpreter::{lex_error::LexErrorKind, lexer::Lexer, token::{Token, TokenType}};
 
 const INPUT: &str = r#"
     var x = 1;
@@ -18,7 +18,7 @@ const INPUT: &str = r#"
 #[test]
 fn check_lexer_tokenize_fn() {
     let code = String::from(INPUT);
-    let mut lexer = Lexer::new(code.clone());
+    let mut lexer = Lexer::new(&code);
 
     let result = lexer.tokenize();
     assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
@@ -28,7 +28,7 @@ fn check_lexer_tokenize_fn() {
         // var x = 1;
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "x".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "1".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
 
@@ -45,9 +45,9 @@ fn check_lexer_tokenize_fn() {
         // var resultado = n1 + n2;
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "resultado".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::IDENTIFIER, "n1".to_string()),
-        Token::new(TokenType::OPERATOR, "+".to_string()),
+        Token::new(TokenType::PLUS, "+".to_string()),
         Token::new(TokenType::IDENTIFIER, "n2".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
 
@@ -64,15 +64,15 @@ fn check_lexer_tokenize_fn() {
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "índice".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::NUMBER, "0".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
         Token::new(TokenType::IDENTIFIER, "índice".to_string()),
-        Token::new(TokenType::OPERATOR, "<".to_string()),
+        Token::new(TokenType::LESS, "<".to_string()),
         Token::new(TokenType::NUMBER, "10".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
         Token::new(TokenType::IDENTIFIER, "índice".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "+=".to_string()),
+        Token::new(TokenType::PLUS_EQUAL, "+=".to_string()),
         Token::new(TokenType::NUMBER, "1".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
 
@@ -81,7 +81,7 @@ fn check_lexer_tokenize_fn() {
 
         // x = soma(x, índice);
         Token::new(TokenType::IDENTIFIER, "x".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::IDENTIFIER, "soma".to_string()),
         Token::new(TokenType::LPAREN, "(".to_string()),
         Token::new(TokenType::IDENTIFIER, "x".to_string()),
@@ -103,7 +103,7 @@ fn check_lexer_tokenize_fn() {
         // var y = "hello world";
         Token::new(TokenType::KEYWORD, "var".to_string()),
         Token::new(TokenType::IDENTIFIER, "y".to_string()),
-        Token::new(TokenType::ASSIGNMENT, "=".to_string()),
+        Token::new(TokenType::EQUAL, "=".to_string()),
         Token::new(TokenType::STRING, "hello world".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
 
@@ -113,6 +113,8 @@ fn check_lexer_tokenize_fn() {
         Token::new(TokenType::IDENTIFIER, "y".to_string()),
         Token::new(TokenType::RPAREN, ")".to_string()),
         Token::new(TokenType::SEMICOLON, ";".to_string()),
+
+        Token::new(TokenType::EOF, "".to_string()),
     ];
 
     assert_eq!(tokens.len(), expected_tokens.len(), "Número inesperado de tokens.");
@@ -139,7 +141,7 @@ fn check_lexer_tokenize_fn() {
 #[test]
 fn check_lexer_invalid_characters() {
     let invalid_input = "var x = 1 + $;";  // '$' é um caractere inválido
-    let mut lexer = Lexer::new(invalid_input.to_string());
+    let mut lexer = Lexer::new(invalid_input);
 
     let result = lexer.tokenize();
 
@@ -152,7 +154,7 @@ fn check_lexer_invalid_characters() {
 #[test]
 fn check_lexer_assignment_compound() {
     let code = "var x = 10; x += 5;";
-    let mut lexer = Lexer::new(code.to_string());
+    let mut lexer = Lexer::new(code);
 
     let result = lexer.tokenize();
     assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
@@ -162,13 +164,538 @@ fn check_lexer_assignment_compound() {
     // A sequência de tokens para "var x = 10; x += 5;" deverá ser:
     // [0] KEYWORD "var"
     // [1] IDENTIFIER "x"
-    // [2] ASSIGNMENT "="
+    // [2] EQUAL "="
     // [3] NUMBER "10"
     // [4] SEMICOLON ";"
     // [5] IDENTIFIER "x"
-    // [6] ASSIGNMENT "+="   <-- este é o token composto
+    // [6] PLUS_EQUAL "+="   <-- este é o token composto
     // [7] NUMBER "5"
     // [8] SEMICOLON ";"
-    assert_eq!(tokens[6]._type, TokenType::ASSIGNMENT);
+    assert_eq!(tokens[6]._type, TokenType::PLUS_EQUAL);
     assert_eq!(tokens[6].value, "+=");
-}
\ No newline at end of file
+}
+
+#[test]
+fn check_lexer_token_spans() {
+    let code = "var x = 1;\nx += 'olá';";
+    let mut lexer = Lexer::with_file_id(code, 7);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let tokens = result.unwrap();
+
+    // Cada token: (início, fim, linha inicial, coluna inicial, linha final, coluna final)
+    let expected_spans = vec![
+        (0, 3, 1, 1, 1, 4),    // var
+        (4, 5, 1, 5, 1, 6),    // x
+        (6, 7, 1, 7, 1, 8),    // =
+        (8, 9, 1, 9, 1, 10),   // 1
+        (9, 10, 1, 10, 1, 11), // ;
+        (11, 12, 2, 1, 2, 2),  // x
+        (13, 15, 2, 3, 2, 5),  // +=
+        (16, 22, 2, 6, 2, 11), // 'olá' (o 'á' ocupa dois bytes)
+        (22, 23, 2, 11, 2, 12), // ;
+        (23, 23, 2, 12, 2, 12)  // EOF
+    ];
+
+    assert_eq!(tokens.len(), expected_spans.len(), "Número inesperado de tokens.");
+
+    for (token, (start, end, start_line, start_column, end_line, end_column)) in tokens.iter().zip(expected_spans) {
+        assert_eq!(token.span.file_id, 7, "Arquivo incorreto no token {:?}", token);
+        assert_eq!((token.span.start, token.span.end), (start, end), "Bytes incorretos no token {:?}", token);
+        assert_eq!((token.span.start_line, token.span.start_column), (start_line, start_column), "Início incorreto no token {:?}", token);
+        assert_eq!((token.span.end_line, token.span.end_column), (end_line, end_column), "Fim incorreto no token {:?}", token);
+        assert_eq!(&code[token.span.start..token.span.end].replace('\'', ""), &token.value, "Trecho incorreto no token {:?}", token);
+    }
+}
+
+#[test]
+fn check_lexer_identifier_at_end_of_input() {
+    let mut lexer = Lexer::new("x + abc");
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let values: Vec<String> = result.unwrap().into_iter().map(|token| token.value).collect();
+    assert_eq!(values, vec!["x", "+", "abc", ""]);
+}
+
+#[test]
+fn check_lexer_comparison_and_logical_operators() {
+    let code = "a == b != c <= d >= e < f > g && !h || i";
+    let mut lexer = Lexer::new(code);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let operators: Vec<TokenType> = result.unwrap()
+        .into_iter()
+        .filter(|token| token._type != TokenType::IDENTIFIER && token._type != TokenType::EOF)
+        .map(|token| token._type)
+        .collect();
+
+    assert_eq!(operators, vec![
+        TokenType::EQUAL_EQUAL,
+        TokenType::BANG_EQUAL,
+        TokenType::LESS_EQUAL,
+        TokenType::GREATER_EQUAL,
+        TokenType::LESS,
+        TokenType::GREATER,
+        TokenType::AMPERSAND_AMPERSAND,
+        TokenType::BANG,
+        TokenType::PIPE_PIPE,
+    ]);
+}
+
+#[test]
+fn check_lexer_multi_char_operator_span() {
+    let mut lexer = Lexer::new("a <= b");
+    let tokens = lexer.tokenize().unwrap();
+
+    assert_eq!(tokens[1].value, "<=");
+    assert_eq!((tokens[1].span.start, tokens[1].span.end), (2, 4));
+    assert_eq!((tokens[1].span.start_column, tokens[1].span.end_column), (3, 5));
+}
+
+#[test]
+fn check_lexer_arithmetic_and_bitwise_operators() {
+    let code = "a % b ** c & d | e ^ ~f << g >> h && i || j";
+    let mut lexer = Lexer::new(code);
+
+    let operators: Vec<(TokenType, String)> = lexer.tokenize().unwrap()
+        .into_iter()
+        .filter(|token| token._type != TokenType::IDENTIFIER && token._type != TokenType::EOF)
+        .map(|token| (token._type, token.value))
+        .collect();
+
+    let expected = vec![
+        (TokenType::PERCENT, "%"),
+        (TokenType::STAR_STAR, "**"),
+        (TokenType::AMPERSAND, "&"),
+        (TokenType::PIPE, "|"),
+        (TokenType::CARET, "^"),
+        (TokenType::TILDE, "~"),
+        (TokenType::LESS_LESS, "<<"),
+        (TokenType::GREATER_GREATER, ">>"),
+        (TokenType::AMPERSAND_AMPERSAND, "&&"),
+        (TokenType::PIPE_PIPE, "||"),
+    ];
+    let expected: Vec<(TokenType, String)> = expected.into_iter()
+        .map(|(_type, value)| (_type, value.to_string()))
+        .collect();
+
+    assert_eq!(operators, expected);
+}
+
+#[test]
+fn check_lexer_compound_assignments() {
+    let code = "a %= 1; a **= 2; a &= 3; a |= 4; a ^= 5; a <<= 6; a >>= 7; a <<=8";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let assignments: Vec<TokenType> = tokens.iter()
+        .filter(|token| !matches!(token._type, TokenType::IDENTIFIER | TokenType::NUMBER | TokenType::SEMICOLON | TokenType::EOF))
+        .map(|token| token._type.clone())
+        .collect();
+    assert_eq!(assignments, vec![
+        TokenType::PERCENT_EQUAL,
+        TokenType::STAR_STAR_EQUAL,
+        TokenType::AMPERSAND_EQUAL,
+        TokenType::PIPE_EQUAL,
+        TokenType::CARET_EQUAL,
+        TokenType::LESS_LESS_EQUAL,
+        TokenType::GREATER_GREATER_EQUAL,
+        TokenType::LESS_LESS_EQUAL,
+    ]);
+
+    let last = &tokens[tokens.len() - 3];
+    assert_eq!((last.span.start, last.span.end), (code.len() - 4, code.len() - 1));
+}
+
+#[test]
+fn check_lexer_int_divide() {
+    // `div=` é a atribuição composta, mas `div ==` e `divisor` não
+    let code = "a div b; a div= 2; a div == b; divisor";
+    let mut lexer = Lexer::new(code);
+    let tokens = lexer.tokenize().unwrap();
+
+    let tokens: Vec<(TokenType, &str)> = tokens.iter()
+        .filter(|token| !matches!(token._type, TokenType::NUMBER | TokenType::SEMICOLON | TokenType::EOF))
+        .map(|token| (token._type.clone(), token.value.as_str()))
+        .collect();
+    assert_eq!(tokens, vec![
+        (TokenType::IDENTIFIER, "a"),
+        (TokenType::DIV, "div"),
+        (TokenType::IDENTIFIER, "b"),
+        (TokenType::IDENTIFIER, "a"),
+        (TokenType::DIV_EQUAL, "div="),
+        (TokenType::IDENTIFIER, "a"),
+        (TokenType::DIV, "div"),
+        (TokenType::EQUAL_EQUAL, "=="),
+        (TokenType::IDENTIFIER, "b"),
+        (TokenType::IDENTIFIER, "divisor"),
+    ]);
+
+    let mut lexer = Lexer::new("x div= 3");
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!((tokens[1].span.start, tokens[1].span.end), (2, 6));
+}
+
+#[test]
+fn check_lexer_conditional_keywords() {
+    let mut lexer = Lexer::new("se (x) { } senão se (y) { } senão { } sem");
+    let tokens = lexer.tokenize().unwrap();
+
+    let keywords: Vec<&str> = tokens.iter()
+        .filter(|token| token._type == TokenType::KEYWORD)
+        .map(|token| token.value.as_str())
+        .collect();
+
+    assert_eq!(keywords, vec!["se", "senão", "se", "senão"]);
+    assert_eq!(tokens[tokens.len() - 2]._type, TokenType::IDENTIFIER);
+}
+
+#[test]
+fn check_lexer_float_literals() {
+    let mut lexer = Lexer::new("3.14 1_000.5 1e-9 2.5E+3 7e2");
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let values: Vec<String> = result.unwrap().into_iter().map(|token| token.value).collect();
+    assert_eq!(values, vec!["3.14", "1000.5", "1e-9", "2.5E+3", "7e2", ""]);
+}
+
+#[test]
+fn check_lexer_malformed_float_literals() {
+    for code in ["1.2.3", "1.", "1e", "1e+", "1e5.2", "2e3e4"] {
+        let mut lexer = Lexer::new(code);
+
+        assert!(lexer.tokenize().is_err(), "Lexer não detectou o número malformado '{}'", code);
+    }
+}
+
+#[test]
+fn check_lexer_radix_integer_literals() {
+    let mut lexer = Lexer::new("0x1F 0b1010 0o755 0xdead_BEEF 0");
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let mut tokens = result.unwrap();
+    assert_eq!(tokens.pop().unwrap()._type, TokenType::EOF);
+    assert!(tokens.iter().all(|token| token._type == TokenType::NUMBER));
+
+    let values: Vec<String> = tokens.into_iter().map(|token| token.value).collect();
+    assert_eq!(values, vec!["0x1F", "0b1010", "0o755", "0xdeadBEEF", "0"]);
+}
+
+#[test]
+fn check_lexer_malformed_radix_literals() {
+    for code in ["0x", "0b102", "0o78", "0x1G", "0b_"] {
+        let mut lexer = Lexer::new(code);
+
+        assert!(lexer.tokenize().is_err(), "Lexer não detectou o número malformado '{}'", code);
+    }
+}
+
+#[test]
+fn check_lexer_string_escapes() {
+    let code = r#"'linha\n' "tab\there" "aspas \"duplas\"" 'aspas \'simples\'' "barra \\" "\u{48}\u{1F600}""#;
+    let mut lexer = Lexer::new(code);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let values: Vec<String> = result.unwrap().into_iter().map(|token| token.value).collect();
+    assert_eq!(values, vec![
+        "linha\n",
+        "tab\there",
+        "aspas \"duplas\"",
+        "aspas 'simples'",
+        "barra \\",
+        "H😀",
+        "",
+    ]);
+}
+
+#[test]
+fn check_lexer_invalid_string_escapes() {
+    for code in [r#""\q""#, r#""\u{}""#, r#""\u{110000}""#, r#""\u{41""#, r#""\u41""#] {
+        let mut lexer = Lexer::new(code);
+
+        assert!(lexer.tokenize().is_err(), "Lexer não detectou o escape inválido em {}", code);
+    }
+
+    let mut lexer = Lexer::new("var x = 1;\nvar y = 'a\\q';");
+    let error = lexer.tokenize().unwrap_err();
+    assert_eq!(error.to_string(), "Sequência de escape inválida '\\q' na linha 2 e coluna 11");
+}
+
+#[test]
+fn check_lexer_escaped_delimiter_does_not_close_string() {
+    let mut lexer = Lexer::new(r#""aberta\""#);
+
+    assert!(lexer.tokenize().is_err(), "Lexer não detectou a string sem fechamento");
+}
+
+#[test]
+fn check_lexer_fstring_tokens() {
+    let code = r#"f"Você escolheu {número}, eu {{escolhi}} {soma(a, b) * 2}.""#;
+    let mut lexer = Lexer::new(code);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let tokens: Vec<(TokenType, String)> = result.unwrap()
+        .into_iter()
+        .map(|token| (token._type, token.value))
+        .collect();
+
+    let expected = vec![
+        (TokenType::FSTRING_START, "f\""),
+        (TokenType::FSTRING_TEXT, "Você escolheu "),
+        (TokenType::INTERPOLATION_START, "{"),
+        (TokenType::IDENTIFIER, "número"),
+        (TokenType::INTERPOLATION_END, "}"),
+        (TokenType::FSTRING_TEXT, ", eu {escolhi} "),
+        (TokenType::INTERPOLATION_START, "{"),
+        (TokenType::IDENTIFIER, "soma"),
+        (TokenType::LPAREN, "("),
+        (TokenType::IDENTIFIER, "a"),
+        (TokenType::COMMA, ","),
+        (TokenType::IDENTIFIER, "b"),
+        (TokenType::RPAREN, ")"),
+        (TokenType::STAR, "*"),
+        (TokenType::NUMBER, "2"),
+        (TokenType::INTERPOLATION_END, "}"),
+        (TokenType::FSTRING_TEXT, "."),
+        (TokenType::FSTRING_END, "\""),
+        (TokenType::EOF, ""),
+    ];
+    let expected: Vec<(TokenType, String)> = expected.into_iter()
+        .map(|(_type, value)| (_type, value.to_string()))
+        .collect();
+
+    assert_eq!(tokens, expected);
+}
+
+#[test]
+fn check_lexer_fstring_text_span() {
+    let mut lexer = Lexer::new("f'a{x}bc'");
+    let tokens = lexer.tokenize().unwrap();
+
+    // f' a { x } bc '
+    let spans: Vec<(usize, usize)> = tokens.iter().map(|token| (token.span.start, token.span.end)).collect();
+    assert_eq!(spans, vec![(0, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 8), (8, 9), (9, 9)]);
+}
+
+#[test]
+fn check_lexer_invalid_fstrings() {
+    for code in [r#"f"aberta {x}"#, r#"f"sem fim {x"#, r#"f"chave } solta""#, r#"f"{x $}""#] {
+        let mut lexer = Lexer::new(code);
+
+        assert!(lexer.tokenize().is_err(), "Lexer não detectou a f-string inválida {}", code);
+    }
+}
+
+#[test]
+fn check_lexer_fstring_nested_braces() {
+    // As chaves da própria expressão não encerram a interpolação
+    let mut lexer = Lexer::new("f'{ {x} }fim'");
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let types: Vec<TokenType> = result.unwrap().into_iter().map(|token| token._type).collect();
+    assert_eq!(types, vec![
+        TokenType::FSTRING_START,
+        TokenType::INTERPOLATION_START,
+        TokenType::LBRACKET,
+        TokenType::IDENTIFIER,
+        TokenType::RBRACKET,
+        TokenType::INTERPOLATION_END,
+        TokenType::FSTRING_TEXT,
+        TokenType::FSTRING_END,
+        TokenType::EOF,
+    ]);
+}
+
+#[test]
+fn check_lexer_comments() {
+    let code = "var x = 10; // comentário de linha\n/* bloco /* aninhado */ ainda comentário */ x /= 2; // fim";
+    let mut lexer = Lexer::new(code);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let values: Vec<String> = result.unwrap().into_iter().map(|token| token.value).collect();
+    assert_eq!(values, vec!["var", "x", "=", "10", ";", "x", "/=", "2", ";", ""]);
+}
+
+#[test]
+fn check_lexer_comment_between_operator_and_equals() {
+    // O '=' só é unido a um operador imediatamente anterior
+    let mut lexer = Lexer::new("a + /* c */ = b");
+
+    let values: Vec<String> = lexer.tokenize().unwrap().into_iter().map(|token| token.value).collect();
+    assert_eq!(values, vec!["a", "+", "=", "b", ""]);
+}
+
+#[test]
+fn check_lexer_unterminated_block_comment() {
+    let mut lexer = Lexer::new("var x = 1;\n  /* aberto /* aninhado */\nvar y = 2;");
+
+    let error = lexer.tokenize().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Erro: esperado fechamento do comentário (*/) iniciado na linha 2 e coluna 3 antes do fim do arquivo."
+    );
+}
+
+#[test]
+fn check_lexer_doc_comments() {
+    let code = "/// Soma dois números.\n///Sem espaço\n//// comentário comum\n// outro comum\nvar x = 1; /// depois";
+    let mut lexer = Lexer::new(code);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let tokens = result.unwrap();
+    let docs: Vec<&Token> = tokens.iter().filter(|token| token._type == TokenType::DOC_COMMENT).collect();
+
+    assert_eq!(docs.len(), 3);
+    assert_eq!(docs[0].value, "Soma dois números.");
+    assert_eq!(docs[1].value, "Sem espaço");
+    assert_eq!(docs[2].value, "depois");
+    assert_eq!((docs[0].span.start, docs[0].span.end), (0, "/// Soma dois números.".len()));
+    assert_eq!(tokens.len(), 9);
+}
+
+#[test]
+fn check_lexer_large_input() {
+    // Um script gerado com algumas centenas de KB deve ser lido em tempo linear
+    let code = "var número_é = 1_000 + soma(x, 'olá');\n".repeat(10_000);
+    let mut lexer = Lexer::new(&code);
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+
+    let tokens = result.unwrap();
+    assert_eq!(tokens.len(), 12 * 10_000 + 1);
+
+    let last = &tokens[tokens.len() - 2];
+    assert_eq!((last.span.start_line, last.span.end), (10_000, code.len() - 1));
+}
+
+#[test]
+fn check_lexer_next_token() {
+    let code = String::from("x += f\"{y}\"; // fim\n");
+    let mut lexer = Lexer::new(&code);
+
+    let first = lexer.next_token().unwrap().unwrap();
+    assert_eq!((first._type, first.value.as_str()), (TokenType::IDENTIFIER, "x"));
+
+    let second = lexer.next_token().unwrap().unwrap();
+    assert_eq!((second._type, second.value.as_str()), (TokenType::PLUS_EQUAL, "+="));
+    assert_eq!((second.span.start, second.span.end), (2, 4));
+
+    // Os tokens restantes da f-string são entregues um a um
+    let types: Vec<TokenType> = lexer.by_ref().map(|token| token.unwrap()._type).collect();
+    assert_eq!(types, vec![
+        TokenType::FSTRING_START,
+        TokenType::INTERPOLATION_START,
+        TokenType::IDENTIFIER,
+        TokenType::INTERPOLATION_END,
+        TokenType::FSTRING_END,
+        TokenType::SEMICOLON,
+        TokenType::EOF,
+    ]);
+    assert!(lexer.next_token().is_none());
+}
+
+#[test]
+fn check_lexer_iterator_stops_after_error() {
+    // Os tokens anteriores ao erro continuam disponíveis
+    let code = String::from("var x = 1; @ var y = 2;");
+    let mut lexer = Lexer::new(&code);
+
+    let tokens: Vec<Token> = lexer.by_ref().map_while(Result::ok).collect();
+    assert_eq!(tokens.len(), 5);
+    assert!(lexer.next().is_none());
+
+    let lexer = Lexer::new(&code);
+    assert_eq!(lexer.filter(Result::is_err).count(), 1);
+}
+
+#[test]
+fn check_lexer_error_kinds() {
+    let cases = [
+        ("x @ y", LexErrorKind::InvalidChar { found: '@' }, (2, 3)),
+        ("'abc", LexErrorKind::UnterminatedString { delim: '\'' }, (0, 4)),
+        ("'a\\qb'", LexErrorKind::InvalidEscape { sequence: "\\q".to_string() }, (2, 4)),
+        ("\"abc\\", LexErrorKind::UnterminatedString { delim: '"' }, (0, 5)),
+        ("f'{x}\\", LexErrorKind::UnterminatedString { delim: '\'' }, (0, 6)),
+        ("'\\q abc", LexErrorKind::UnterminatedString { delim: '\'' }, (0, 7)),
+        ("1.2.3 + 1", LexErrorKind::MalformedNumber { literal: "1.2.3".to_string() }, (0, 5)),
+        ("/* /* */", LexErrorKind::UnterminatedComment, (0, 8)),
+        ("f'a}b'", LexErrorKind::UnmatchedBrace, (3, 4)),
+    ];
+
+    for (code, kind, (start, end)) in cases {
+        let mut lexer = Lexer::new(code);
+        let error = lexer.tokenize().unwrap_err();
+
+        assert_eq!(error.kind, kind, "Erro inesperado em {}", code);
+        assert_eq!((error.span.start, error.span.end), (start, end), "Span inesperado em {}", code);
+    }
+}
+
+#[test]
+fn check_lexer_error_recovery() {
+    let code = "var x = 1 @ 2;\nvar s = 'a\\qb';\nvar f = f\"{y $ 1}\";\nvar n = 0b102;\nimprimir(x);";
+    let mut lexer = Lexer::new(code).with_recovery();
+
+    let result = lexer.tokenize();
+    assert!(result.is_ok(), "Lexer falhou: {:?}", result.err());
+    let tokens = result.unwrap();
+
+    let invalid: Vec<&str> = tokens.iter()
+        .filter(|token| token._type == TokenType::ERROR)
+        .map(|token| token.value.as_str())
+        .collect();
+    assert_eq!(invalid, vec!["@", "'a\\qb'", "f\"{y $ 1}\"", "0b102"]);
+
+    let kinds: Vec<&LexErrorKind> = lexer.errors().iter().map(|error| &error.kind).collect();
+    assert_eq!(kinds, vec![
+        &LexErrorKind::InvalidChar { found: '@' },
+        &LexErrorKind::InvalidEscape { sequence: "\\q".to_string() },
+        &LexErrorKind::InvalidChar { found: '$' },
+        &LexErrorKind::MalformedNumber { literal: "0b102".to_string() },
+    ]);
+    assert_eq!(lexer.errors()[2].span.start_line, 3);
+
+    // O restante do código continua sendo lido normalmente
+    let last: Vec<&str> = tokens[tokens.len() - 6..tokens.len() - 1].iter().map(|token| token.value.as_str()).collect();
+    assert_eq!(last, vec!["imprimir", "(", "x", ")", ";"]);
+}
+
+#[test]
+fn check_lexer_eof_token() {
+    // O EOF fica no fim real do código, depois dos espaços e comentários finais
+    let mut lexer = Lexer::new("var x = 1;\nx = 2; // fim\n\n");
+    let tokens = lexer.tokenize().unwrap();
+
+    let eof = tokens.last().unwrap();
+    assert_eq!(eof._type, TokenType::EOF);
+    assert_eq!((eof.span.start, eof.span.end), (26, 26));
+    assert_eq!((eof.span.start_line, eof.span.start_column), (4, 1));
+    assert_eq!(tokens.iter().filter(|token| token._type == TokenType::EOF).count(), 1);
+
+    let mut lexer = Lexer::new("");
+    let eof = lexer.next().unwrap().unwrap();
+    assert_eq!(eof._type, TokenType::EOF);
+    assert_eq!((eof.span.start, eof.span.start_line, eof.span.start_column), (0, 1, 1));
+    assert!(lexer.next().is_none());
+}